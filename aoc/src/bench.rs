@@ -0,0 +1,158 @@
+use std::{collections::BTreeMap, fmt, time::Duration};
+
+/// Step of a solver, timed separately.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.name() == name)
+    }
+}
+
+/// Samples of every phase of a day, one per iteration.
+#[derive(Clone, Debug, Default)]
+pub struct Timings {
+    pub parse: Vec<Duration>,
+    pub part1: Vec<Duration>,
+    pub part2: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn samples(&self, phase: Phase) -> &[Duration] {
+        match phase {
+            Phase::Parse => &self.parse,
+            Phase::Part1 => &self.part1,
+            Phase::Part2 => &self.part2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples.
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+
+        Some(Stats {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// Median time of every phase of every day, as saved by a previous run.
+///
+/// Stored as plain text, one `<day> <phase> <median_ns>` line per entry.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, Phase), Duration>,
+}
+
+impl Baseline {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut medians = BTreeMap::new();
+
+        for (i, line) in aoc_common::lines(content).enumerate() {
+            let invalid = || format!("Invalid baseline line {}: {line}", i + 1);
+
+            let [day, phase, nanos] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+                return Err(invalid());
+            };
+            let day = day.parse().map_err(|_| invalid())?;
+            let phase = Phase::from_name(phase).ok_or_else(invalid)?;
+            let nanos = nanos.parse().map_err(|_| invalid())?;
+
+            medians.insert((day, phase), Duration::from_nanos(nanos));
+        }
+
+        Ok(Baseline { medians })
+    }
+
+    pub fn get(&self, day: u8, phase: Phase) -> Option<Duration> {
+        self.medians.get(&(day, phase)).copied()
+    }
+
+    pub fn set(&mut self, day: u8, phase: Phase, median: Duration) {
+        self.medians.insert((day, phase), median);
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((day, phase), median) in &self.medians {
+            writeln!(f, "{day} {} {}", phase.name(), median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+/// Relative change from `baseline` to `current`, in percent.
+pub fn change_percent(baseline: Duration, current: Duration) -> f64 {
+    (current.as_secs_f64() / baseline.as_secs_f64() - 1.) * 100.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_nanos);
+        assert_eq!(
+            Stats::new(&samples),
+            Some(Stats {
+                min: Duration::from_nanos(1),
+                median: Duration::from_nanos(3),
+                max: Duration::from_nanos(5),
+            })
+        );
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let mut baseline = Baseline::default();
+        baseline.set(12, Phase::Part2, Duration::from_nanos(1500));
+        baseline.set(8, Phase::Parse, Duration::from_nanos(20));
+
+        let text = baseline.to_string();
+        assert_eq!(text, "8 parse 20\n12 part2 1500\n");
+        assert_eq!(Baseline::parse(&text), Ok(baseline));
+    }
+
+    #[test]
+    fn test_baseline_invalid() {
+        assert!(Baseline::parse("8 parse").is_err());
+        assert!(Baseline::parse("8 part3 20").is_err());
+        assert!(Baseline::parse("8 parse twenty").is_err());
+    }
+
+    #[test]
+    fn test_change_percent() {
+        let change = change_percent(Duration::from_nanos(100), Duration::from_nanos(125));
+        assert!((change - 25.).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,512 @@
+use std::path::PathBuf;
+
+use aoc::output::Format;
+
+pub const USAGE: &str = "\
+Usage: aoc run <day> [--part <1|2>] [--input <path> | --example] [--stream]
+               [--top <k> | --report] [--format <text|json>]
+       aoc run --all [--part <1|2>] [--example] [--jobs <n>] [--format <text|json>]
+       aoc fetch <day>
+       aoc submit <day> <1|2>
+       aoc bench [<day>] [--iterations <n>] [--input <path>] [--baseline <path>]
+                 [--threshold <percent>] [--save [--force]]
+
+Commands:
+  run <day>        Solve a day, reading the puzzle input from stdin if it's piped,
+                   or from inputs/dNN.txt
+  run --all        Solve every day with an input in inputs/dNN.txt concurrently,
+                   and summarize the answers and timings
+  fetch <day>      Download the puzzle input of a day to inputs/dNN.txt, unless it's
+                   already there, authenticated by the session cookie in $AOC_SESSION
+  submit <day> <1|2>
+                   Solve a part from inputs/dNN.txt and submit the answer, unless
+                   inputs/history.txt tells it's wrong, then record the attempt
+  bench [<day>]    Time parsing and both parts of a day, or of every day with
+                   an input in inputs/dNN.txt
+
+Options:
+  --part <1|2>     Only solve this part
+  --input <path>   Read the puzzle input from this file
+  --example        Solve the example of the puzzle statement, and check the answers
+  --stream         Process the input line by line without holding it in memory,
+                   for days 1, 2, 3, 4, 9 and 10
+  --top <k>        Report the k elves carrying the most calories instead, for day 1
+  --report         Describe the input instead, for day 3: the items shared by
+                   compartments and groups, and how to regroup the elves, and
+                   day 4: the sections covered by each line of any number of ranges
+  --jobs <n>       Number of threads solving days (default: available parallelism)
+  --format <fmt>   Print answers as text (default) or as JSON records,
+                   timings exclude parsing
+  --iterations <n> Number of timed runs (default: 10)
+  --baseline <path>
+                   Medians to compare against (default: bench_baseline.txt)
+  --threshold <percent>
+                   Slowdown over the baseline flagged as a regression (default: 10)
+  --save           Write this run's medians to the baseline, unless some are
+                   regressions
+  --force          Save the baseline even if there are regressions
+  -h, --help       Print this help";
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+pub const DEFAULT_BASELINE: &str = "bench_baseline.txt";
+pub const DEFAULT_THRESHOLD: f64 = 10.;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    RunAll(RunAllArgs),
+    Fetch(u8),
+    Submit { day: u8, part: u8 },
+    Bench(BenchArgs),
+    Help,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    pub day: u8,
+    /// Both parts are solved if `None`.
+    pub part: Option<u8>,
+    /// Piped stdin or the cached input is read if `None`.
+    pub input: Option<PathBuf>,
+    /// Exclusive with `input`.
+    pub example: bool,
+    pub stream: bool,
+    /// Day 1 only, reporting the elves carrying the most instead of answering.
+    pub top: Option<usize>,
+    /// Describing the input instead of answering, for the days which can.
+    pub report: bool,
+    pub format: Format,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunAllArgs {
+    /// Both parts are solved if `None`.
+    pub part: Option<u8>,
+    pub example: bool,
+    /// Available parallelism if `None`.
+    pub jobs: Option<usize>,
+    pub format: Format,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArgs {
+    /// Every day with an input is benchmarked if `None`.
+    pub day: Option<u8>,
+    pub iterations: usize,
+    /// Only allowed along with a day.
+    pub input: Option<PathBuf>,
+    pub baseline: PathBuf,
+    /// In percent.
+    pub threshold: f64,
+    pub save: bool,
+    /// Save even if there are regressions. Only allowed along with `save`.
+    pub force: bool,
+}
+
+/// Parses the command line, program name excluded.
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => parse_run(args),
+        Some("fetch") => parse_fetch(args).map(Command::Fetch),
+        Some("submit") => parse_submit(args),
+        Some("bench") => parse_bench(args).map(Command::Bench),
+        Some("-h" | "--help") | None => Ok(Command::Help),
+        Some(cmd) => Err(format!("Unknown command: {cmd}")),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut all = false;
+    let mut part = None;
+    let mut input = None;
+    let mut example = false;
+    let mut stream = false;
+    let mut jobs = None;
+    let mut top = None;
+    let mut report = false;
+    let mut format = Format::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = value_of(&mut args, "--part")?;
+                part = match value.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("Invalid part: {value}")),
+                };
+            }
+            "--input" => input = Some(value_of(&mut args, "--input")?.into()),
+            "--example" => example = true,
+            "--stream" => stream = true,
+            "--all" => all = true,
+            "--jobs" => {
+                let value = value_of(&mut args, "--jobs")?;
+                jobs = match value.parse() {
+                    Ok(n @ 1..) => Some(n),
+                    _ => return Err(format!("Invalid number of jobs: {value}")),
+                };
+            }
+            "--top" => {
+                let value = value_of(&mut args, "--top")?;
+                top = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Invalid count: {value}"))?,
+                );
+            }
+            "--report" => report = true,
+            "--format" => {
+                let value = value_of(&mut args, "--format")?;
+                format = Format::parse(&value).ok_or(format!("Invalid format: {value}"))?;
+            }
+            _ if arg.starts_with('-') => return Err(format!("Unknown option: {arg}")),
+            _ if day.is_none() => day = Some(parse_day(&arg)?),
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    if example && input.is_some() {
+        return Err("--example and --input are exclusive".to_string());
+    }
+
+    if all {
+        if day.is_some() {
+            return Err("--all excludes a day".to_string());
+        }
+        if input.is_some() || stream {
+            return Err("--input and --stream require a day".to_string());
+        }
+        if top.is_some() {
+            return Err("--top requires day 1".to_string());
+        }
+        if report {
+            return Err("--report requires a day".to_string());
+        }
+        return Ok(Command::RunAll(RunAllArgs {
+            part,
+            example,
+            jobs,
+            format,
+        }));
+    }
+
+    if jobs.is_some() {
+        return Err("--jobs requires --all".to_string());
+    }
+
+    let day = day.ok_or("Missing day")?;
+    if top.is_some() {
+        if day != 1 {
+            return Err("--top is only supported by day 1".to_string());
+        }
+        if part.is_some() {
+            return Err("--top excludes --part".to_string());
+        }
+    }
+    if report && (part.is_some() || stream || top.is_some()) {
+        return Err("--report excludes --part, --stream and --top".to_string());
+    }
+
+    Ok(Command::Run(RunArgs {
+        day,
+        part,
+        input,
+        example,
+        stream,
+        top,
+        report,
+        format,
+    }))
+}
+
+fn parse_fetch(args: impl Iterator<Item = String>) -> Result<u8, String> {
+    let mut day = None;
+
+    for arg in args {
+        match arg.as_str() {
+            _ if arg.starts_with('-') => return Err(format!("Unknown option: {arg}")),
+            _ if day.is_none() => day = Some(parse_day(&arg)?),
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    day.ok_or_else(|| "Missing day".to_string())
+}
+
+fn parse_submit(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day = parse_day(&args.next().ok_or("Missing day")?)?;
+    let part = match args.next().as_deref() {
+        Some("1") => 1,
+        Some("2") => 2,
+        Some(value) => return Err(format!("Invalid part: {value}")),
+        None => return Err("Missing part".to_string()),
+    };
+    if let Some(arg) = args.next() {
+        return Err(format!("Unexpected argument: {arg}"));
+    }
+
+    Ok(Command::Submit { day, part })
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+    let mut bench = BenchArgs {
+        day: None,
+        iterations: DEFAULT_ITERATIONS,
+        input: None,
+        baseline: DEFAULT_BASELINE.into(),
+        threshold: DEFAULT_THRESHOLD,
+        save: false,
+        force: false,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                let value = value_of(&mut args, "--iterations")?;
+                bench.iterations = match value.parse() {
+                    Ok(n @ 1..) => n,
+                    _ => return Err(format!("Invalid number of iterations: {value}")),
+                };
+            }
+            "--input" => bench.input = Some(value_of(&mut args, "--input")?.into()),
+            "--baseline" => bench.baseline = value_of(&mut args, "--baseline")?.into(),
+            "--threshold" => {
+                let value = value_of(&mut args, "--threshold")?;
+                bench.threshold = match value.parse() {
+                    Ok(t) if t >= 0. => t,
+                    _ => return Err(format!("Invalid threshold: {value}")),
+                };
+            }
+            "--save" => bench.save = true,
+            "--force" => bench.force = true,
+            _ if arg.starts_with('-') => return Err(format!("Unknown option: {arg}")),
+            _ if bench.day.is_none() => bench.day = Some(parse_day(&arg)?),
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    if bench.input.is_some() && bench.day.is_none() {
+        return Err("--input requires a day".to_string());
+    }
+    if bench.force && !bench.save {
+        return Err("--force requires --save".to_string());
+    }
+
+    Ok(bench)
+}
+
+fn value_of(args: &mut impl Iterator<Item = String>, option: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("Missing value for {option}"))
+}
+
+fn parse_day(arg: &str) -> Result<u8, String> {
+    match arg.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("Invalid day: {arg}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(
+            parse(args(
+                "run 11 --part 2 --input in.txt --stream --format json"
+            )),
+            Ok(Command::Run(RunArgs {
+                day: 11,
+                part: Some(2),
+                input: Some("in.txt".into()),
+                example: false,
+                stream: true,
+                top: None,
+                report: false,
+                format: Format::Json,
+            }))
+        );
+        assert_eq!(
+            parse(args("run 3")),
+            Ok(Command::Run(RunArgs {
+                day: 3,
+                part: None,
+                input: None,
+                example: false,
+                stream: false,
+                top: None,
+                report: false,
+                format: Format::Text,
+            }))
+        );
+        assert_eq!(
+            parse(args("run 13 --example")),
+            Ok(Command::Run(RunArgs {
+                day: 13,
+                part: None,
+                input: None,
+                example: true,
+                stream: false,
+                top: None,
+                report: false,
+                format: Format::Text,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_top() {
+        assert_eq!(
+            parse(args("run 1 --top 5 --format json")),
+            Ok(Command::Run(RunArgs {
+                day: 1,
+                part: None,
+                input: None,
+                example: false,
+                stream: false,
+                top: Some(5),
+                report: false,
+                format: Format::Json,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        assert_eq!(
+            parse(args("run 3 --report --example")),
+            Ok(Command::Run(RunArgs {
+                day: 3,
+                part: None,
+                input: None,
+                example: true,
+                stream: false,
+                top: None,
+                report: true,
+                format: Format::Text,
+            }))
+        );
+        assert_eq!(
+            parse(args("run 4 --report --input in.txt")),
+            Ok(Command::Run(RunArgs {
+                day: 4,
+                part: None,
+                input: Some("in.txt".into()),
+                example: false,
+                stream: false,
+                top: None,
+                report: true,
+                format: Format::Text,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_run_all() {
+        assert_eq!(
+            parse(args("run --all --part 1 --example --jobs 3 --format json")),
+            Ok(Command::RunAll(RunAllArgs {
+                part: Some(1),
+                example: true,
+                jobs: Some(3),
+                format: Format::Json,
+            }))
+        );
+        assert!(parse(args("run 3 --all")).is_err());
+        assert!(parse(args("run --all --input in.txt")).is_err());
+        assert!(parse(args("run --all --stream")).is_err());
+        assert!(parse(args("run --all --jobs 0")).is_err());
+        assert!(parse(args("run 3 --jobs 2")).is_err());
+    }
+
+    #[test]
+    fn test_fetch() {
+        assert_eq!(parse(args("fetch 7")), Ok(Command::Fetch(7)));
+        assert!(parse(args("fetch")).is_err());
+        assert!(parse(args("fetch 7 8")).is_err());
+        assert!(parse(args("fetch 7 --all")).is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        assert_eq!(
+            parse(args("submit 7 2")),
+            Ok(Command::Submit { day: 7, part: 2 })
+        );
+        assert!(parse(args("submit 7")).is_err());
+        assert!(parse(args("submit 7 3")).is_err());
+        assert!(parse(args("submit 7 1 2")).is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        assert_eq!(
+            parse(args("bench")),
+            Ok(Command::Bench(BenchArgs {
+                day: None,
+                iterations: DEFAULT_ITERATIONS,
+                input: None,
+                baseline: DEFAULT_BASELINE.into(),
+                threshold: DEFAULT_THRESHOLD,
+                save: false,
+                force: false,
+            }))
+        );
+        assert_eq!(
+            parse(args(
+                "bench 8 --iterations 50 --input in.txt --baseline b.txt --threshold 5 --save --force"
+            )),
+            Ok(Command::Bench(BenchArgs {
+                day: Some(8),
+                iterations: 50,
+                input: Some("in.txt".into()),
+                baseline: "b.txt".into(),
+                threshold: 5.,
+                save: true,
+                force: true,
+            }))
+        );
+        assert!(parse(args("bench --input in.txt")).is_err());
+        assert!(parse(args("bench --iterations 0")).is_err());
+        assert!(parse(args("bench --threshold -1")).is_err());
+        assert!(parse(args("bench --force")).is_err());
+    }
+
+    #[test]
+    fn test_help() {
+        assert_eq!(parse(args("")), Ok(Command::Help));
+        assert_eq!(parse(args("--help")), Ok(Command::Help));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse(args("jump 3")).is_err());
+        assert!(parse(args("run")).is_err());
+        assert!(parse(args("run 26")).is_err());
+        assert!(parse(args("run 3 --part 3")).is_err());
+        assert!(parse(args("run 3 --input")).is_err());
+        assert!(parse(args("run 3 4")).is_err());
+        assert!(parse(args("run 3 --format xml")).is_err());
+        assert!(parse(args("run 3 --example --input in.txt")).is_err());
+        assert!(parse(args("run 3 --top 2")).is_err());
+        assert!(parse(args("run 1 --top 2 --part 1")).is_err());
+        assert!(parse(args("run 1 --top many")).is_err());
+        assert!(parse(args("run 1 --top 2 --stream")).is_ok());
+        assert!(parse(args("run --all --top 2")).is_err());
+        assert!(parse(args("run 1 --top 2 --report")).is_err());
+        assert!(parse(args("run 3 --report --stream")).is_err());
+        assert!(parse(args("run 3 --report --part 2")).is_err());
+        assert!(parse(args("run --all --report")).is_err());
+        assert!(parse(args("run 4 --coverage")).is_err());
+    }
+}
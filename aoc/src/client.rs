@@ -0,0 +1,147 @@
+use std::{
+    cell::RefCell,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// Status and body of an HTTP response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The few HTTP requests made to the puzzle's website, authenticated by a session cookie.
+///
+/// Tests use a [`Fake`] instead of the network.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String>;
+
+    /// Posts `form` URL-encoded.
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String>;
+}
+
+/// Sends requests through the `curl` command, which spares us a TLS stack.
+pub struct Curl;
+
+impl Curl {
+    fn request(&self, url: &str, session: &str, args: &[String]) -> Result<Response, String> {
+        // Headers are read from stdin, to keep the session out of the process list
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--location", "--header", "@-"])
+            .args(["--write-out", "\n%{http_code}", url])
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Couldn't run curl: {e}"))?;
+
+        let headers = format!("Cookie: session={session}\nUser-Agent: {USER_AGENT}\n");
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(headers.as_bytes())
+            .map_err(|e| format!("Couldn't write to curl: {e}"))?;
+
+        let output = child
+            .wait_with_output()
+            .map_err(|e| format!("curl failed: {e}"))?;
+        if !output.status.success() {
+            return Err(format!(
+                "curl failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let stdout = String::from_utf8(output.stdout).map_err(|e| e.to_string())?;
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or("Missing status code in curl's output")?;
+
+        Ok(Response {
+            status: status
+                .parse()
+                .map_err(|_| format!("Invalid status code: {status}"))?,
+            body: body.to_string(),
+        })
+    }
+}
+
+impl Http for Curl {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+        self.request(url, session, &[])
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        let args: Vec<_> = form
+            .iter()
+            .flat_map(|(name, value)| ["--data-urlencode".to_string(), format!("{name}={value}")])
+            .collect();
+        self.request(url, session, &args)
+    }
+}
+
+/// Identifies the runner to the website, as its maintainers ask of automated tools.
+pub const USER_AGENT: &str = "aoc-runner (puzzle input fetcher, via curl)";
+
+/// Answers requests from canned responses, and records them.
+#[derive(Debug, Default)]
+pub struct Fake {
+    responses: RefCell<Vec<(String, Response)>>,
+    requests: RefCell<Vec<Request>>,
+}
+
+/// A request received by a [`Fake`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub url: String,
+    /// Empty for a GET.
+    pub form: Vec<(String, String)>,
+}
+
+impl Fake {
+    /// Answers `response` to the next request to `url`.
+    pub fn respond(&self, url: &str, status: u16, body: &str) {
+        self.responses.borrow_mut().push((
+            url.to_string(),
+            Response {
+                status,
+                body: body.to_string(),
+            },
+        ));
+    }
+
+    /// Requests received so far, in order.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.borrow().clone()
+    }
+
+    fn answer(&self, url: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        self.requests.borrow_mut().push(Request {
+            url: url.to_string(),
+            form: form
+                .iter()
+                .map(|&(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        });
+
+        let mut responses = self.responses.borrow_mut();
+        let i = responses
+            .iter()
+            .position(|(u, _)| u == url)
+            .ok_or_else(|| format!("Unexpected request to {url}"))?;
+        Ok(responses.remove(i).1)
+    }
+}
+
+impl Http for Fake {
+    fn get(&self, url: &str, _session: &str) -> Result<Response, String> {
+        self.answer(url, &[])
+    }
+
+    fn post(&self, url: &str, _session: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        self.answer(url, form)
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    hint::black_box,
+    io::BufRead,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{Answer, Example, Format, Reporting, Result, Solver, Streaming};
+
+use crate::{bench::Timings, fetch};
+
+type StreamFn = fn(&mut dyn BufRead, &[u8]) -> Result<Vec<Solution>>;
+type ReportFn = fn(&str, Format) -> Result<String>;
+
+/// Type-erased entry points of a day's solver.
+pub struct Day {
+    pub number: u8,
+    pub example: Example,
+    solve: fn(&str, &[u8]) -> Result<Vec<Solution>>,
+    stream: Option<StreamFn>,
+    report: Option<ReportFn>,
+    bench: fn(&str, usize) -> Result<Timings>,
+}
+
+/// The answer to a part, and the time spent solving it, parsing excluded.
+#[derive(Clone, Debug)]
+pub struct Solution {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Times the answer to a part.
+fn timed(day: u8, part: u8, solve: impl FnOnce() -> Result<Answer>) -> Result<Solution> {
+    let start = Instant::now();
+    let answer = solve()?;
+
+    Ok(Solution {
+        day,
+        part,
+        answer,
+        elapsed: start.elapsed(),
+    })
+}
+
+/// Parses `content` once, then solves each of `parts`.
+fn solve<S: Solver>(content: &str, parts: &[u8]) -> Result<Vec<Solution>> {
+    let input = S::parse(content)?;
+
+    parts
+        .iter()
+        .map(|&part| {
+            timed(S::DAY, part, || match part {
+                1 => S::part1(&input),
+                _ => S::part2(&input),
+            })
+        })
+        .collect()
+}
+
+/// Feeds `reader` line by line, then solves each of `parts`.
+fn stream<S: Solver + Streaming>(reader: &mut dyn BufRead, parts: &[u8]) -> Result<Vec<Solution>> {
+    let state = aoc_common::feed_lines::<S>(reader)?;
+
+    parts
+        .iter()
+        .map(|&part| {
+            timed(S::DAY, part, || match part {
+                1 => <S as Streaming>::part1(&state),
+                _ => <S as Streaming>::part2(&state),
+            })
+        })
+        .collect()
+}
+
+/// Times parsing and both parts separately, `iterations` times.
+fn bench<S: Solver>(content: &str, iterations: usize) -> Result<Timings> {
+    let mut timings = Timings::default();
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let input = S::parse(black_box(content))?;
+        timings.parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&input)?);
+        timings.part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&input)?);
+        timings.part2.push(start.elapsed());
+    }
+
+    Ok(timings)
+}
+
+impl Day {
+    const fn new<S: Solver>() -> Self {
+        Day {
+            number: S::DAY,
+            example: S::EXAMPLE,
+            solve: solve::<S>,
+            stream: None,
+            report: None,
+            bench: bench::<S>,
+        }
+    }
+
+    /// A day which can also be solved from a stream.
+    const fn streaming<S: Solver + Streaming>() -> Self {
+        Day {
+            stream: Some(stream::<S>),
+            ..Day::new::<S>()
+        }
+    }
+
+    /// This day, which can also report on its input.
+    const fn reporting<S: Reporting>(self) -> Self {
+        Day {
+            report: Some(S::report),
+            ..self
+        }
+    }
+
+    /// Solves each of `parts` (1 or 2) of this day, in order.
+    pub fn solve(&self, content: &str, parts: &[u8]) -> Result<Vec<Solution>> {
+        (self.solve)(content, parts)
+    }
+
+    /// Solves each of `parts` of this day without holding the input in memory.
+    /// Timings then exclude most of the work, done while reading.
+    ///
+    /// Returns `None` if this day can't be streamed.
+    pub fn stream(&self, reader: &mut dyn BufRead, parts: &[u8]) -> Option<Result<Vec<Solution>>> {
+        self.stream.map(|stream| stream(reader, parts))
+    }
+
+    /// Describes the input of this day rather than answering.
+    ///
+    /// Returns `None` if this day has no report.
+    pub fn report(&self, content: &str, format: Format) -> Option<Result<String>> {
+        self.report.map(|report| report(content, format))
+    }
+
+    /// Times each phase of this day, `iterations` times.
+    pub fn bench(&self, content: &str, iterations: usize) -> Result<Timings> {
+        (self.bench)(content, iterations)
+    }
+
+    /// Default location of the real puzzle input, in the workspace.
+    pub fn input_path(&self) -> PathBuf {
+        crate::workspace_root()
+            .join(fetch::CACHE_DIR)
+            .join(fetch::input_name(self.number))
+    }
+}
+
+/// Every solved day, in calendar order.
+pub const DAYS: &[Day] = &[
+    Day::streaming::<d01::Day01>(),
+    Day::streaming::<d02::Day02>(),
+    Day::streaming::<d03::Day03>().reporting::<d03::Day03>(),
+    Day::streaming::<d04::Day04>().reporting::<d04::Day04>(),
+    Day::new::<d05::Day05>(),
+    Day::new::<d06::Day06>(),
+    Day::new::<d07::Day07>(),
+    Day::new::<d08::Day08>(),
+    Day::streaming::<d09::Day09>(),
+    Day::streaming::<d10::Day10>(),
+    Day::new::<d11::Day11>(),
+    Day::new::<d12::Day12>(),
+    Day::new::<d13::Day13>(),
+    Day::new::<d14::Day14>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_registered_in_order() {
+        assert!(DAYS.iter().map(|d| d.number).eq(1..=14));
+    }
+
+    #[test]
+    fn streamed_examples_match() {
+        for day in DAYS.iter().filter(|d| d.stream.is_some()) {
+            let mut reader = day.example.input.as_bytes();
+            let solutions = day.stream(&mut reader, &[1, 2]).unwrap().unwrap();
+            for solution in solutions {
+                assert_eq!(
+                    solution.answer.to_string(),
+                    day.example.answer(solution.part)
+                );
+            }
+        }
+    }
+}
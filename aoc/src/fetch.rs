@@ -0,0 +1,238 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::client::{Http, Response};
+
+pub const BASE_URL: &str = "https://adventofcode.com/2022";
+
+/// Environment variable holding the value of the website's `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Where inputs are cached, relative to the [workspace](crate::workspace_root).
+pub const CACHE_DIR: &str = "inputs";
+
+/// Name of the cached input of `day`, in the cache directory.
+pub fn input_name(day: u8) -> String {
+    format!("d{day:02}.txt")
+}
+
+/// Minimum time between two requests to the website, across runs.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Name of the file recording when the last request was sent, in the cache directory.
+const LAST_REQUEST: &str = ".last-request";
+
+/// Downloads puzzle inputs, keeping them in a cache directory.
+pub struct Fetcher<H> {
+    http: H,
+    session: String,
+    cache: PathBuf,
+    base_url: String,
+    min_interval: Duration,
+}
+
+/// Where an input was found.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetched {
+    pub fn path(&self) -> &Path {
+        match self {
+            Fetched::Cached(path) | Fetched::Downloaded(path) => path,
+        }
+    }
+}
+
+impl<H: Http> Fetcher<H> {
+    pub fn new(http: H, session: impl Into<String>, cache: impl Into<PathBuf>) -> Self {
+        Fetcher {
+            http,
+            session: session.into(),
+            cache: cache.into(),
+            base_url: BASE_URL.to_string(),
+            min_interval: MIN_INTERVAL,
+        }
+    }
+
+    pub fn with_base_url(self, base_url: impl Into<String>) -> Self {
+        Fetcher {
+            base_url: base_url.into(),
+            ..self
+        }
+    }
+
+    pub fn with_min_interval(self, min_interval: Duration) -> Self {
+        Fetcher {
+            min_interval,
+            ..self
+        }
+    }
+
+    pub fn http(&self) -> &H {
+        &self.http
+    }
+
+    /// Cached input of `day`, which is downloaded if it isn't there yet.
+    pub fn input(&self, day: u8) -> Result<Fetched, String> {
+        let path = self.cache.join(input_name(day));
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let url = format!("{}/day/{day}/input", self.base_url);
+        let response = self.throttled(|| self.http.get(&url, &self.session))?;
+        match response.status {
+            200 => {}
+            404 => return Err(format!("Day {day} isn't unlocked yet")),
+            400 | 500 => {
+                return Err(format!(
+                    "The website refused the session in ${SESSION_VAR}, it may have expired"
+                ))
+            }
+            status => return Err(format!("{url}: unexpected status {status}")),
+        }
+
+        write_cache(&path, &response.body).map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Posts `answer` to `part` of `day`, see [`crate::submit`] for the response.
+    pub fn answer(&self, day: u8, part: u8, answer: &str) -> Result<Response, String> {
+        let url = format!("{}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        self.throttled(|| {
+            self.http.post(
+                &url,
+                &self.session,
+                &[("level", &level), ("answer", answer)],
+            )
+        })
+    }
+
+    /// Sends a request once `min_interval` has passed since the previous one.
+    fn throttled<T>(&self, request: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+        let stamp = self.cache.join(LAST_REQUEST);
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .map(|secs| UNIX_EPOCH + Duration::from_secs(secs));
+
+        let wait = wait_time(last, SystemTime::now(), self.min_interval);
+        if !wait.is_zero() {
+            eprintln!("Waiting {wait:.0?} between requests to the website");
+            thread::sleep(wait);
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        fs::create_dir_all(&self.cache)
+            .and_then(|()| fs::write(&stamp, now.as_secs().to_string()))
+            .map_err(|e| format!("{}: {e}", stamp.display()))?;
+
+        request()
+    }
+}
+
+/// How long to wait before sending a request, the previous one having been sent at `last`.
+pub fn wait_time(last: Option<SystemTime>, now: SystemTime, min_interval: Duration) -> Duration {
+    let Some(last) = last else {
+        return Duration::ZERO;
+    };
+
+    // A clock going backwards isn't worth waiting for
+    let elapsed = now.duration_since(last).unwrap_or(min_interval);
+    min_interval.saturating_sub(elapsed)
+}
+
+/// Writes `content` to `path` through a temporary file, so that an interrupted write
+/// doesn't leave a truncated input in the cache.
+fn write_cache(path: &Path, content: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("part");
+    fs::write(&partial, content)?;
+    fs::rename(&partial, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Fake;
+
+    /// A fresh cache directory for each test.
+    fn cache(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn fetcher(name: &str) -> Fetcher<Fake> {
+        Fetcher::new(Fake::default(), "secret", cache(name))
+            .with_base_url("http://fake")
+            .with_min_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_download_then_cache() {
+        let fetcher = fetcher("cache");
+        fetcher
+            .http()
+            .respond("http://fake/day/3/input", 200, "vJrwpWtwJgWr\n");
+
+        let fetched = fetcher.input(3).unwrap();
+        assert!(matches!(fetched, Fetched::Downloaded(_)));
+        assert_eq!(
+            fs::read_to_string(fetched.path()).unwrap(),
+            "vJrwpWtwJgWr\n"
+        );
+
+        // The fake has no response left, so a second request would fail
+        assert_eq!(
+            fetcher.input(3).unwrap(),
+            Fetched::Cached(fetched.path().into())
+        );
+        assert_eq!(fetcher.http().requests().len(), 1);
+    }
+
+    #[test]
+    fn test_errors() {
+        let fetcher = fetcher("errors");
+        fetcher.http().respond("http://fake/day/24/input", 404, "");
+        fetcher.http().respond("http://fake/day/1/input", 400, "");
+
+        assert!(fetcher.input(24).unwrap_err().contains("isn't unlocked"));
+        assert!(fetcher.input(1).unwrap_err().contains("session"));
+        // Nothing is cached on failure
+        assert!(fetcher.input(1).is_err());
+        assert_eq!(fetcher.http().requests().len(), 3);
+    }
+
+    #[test]
+    fn test_wait_time() {
+        let interval = Duration::from_secs(5);
+        let now = UNIX_EPOCH + Duration::from_secs(100);
+
+        assert_eq!(wait_time(None, now, interval), Duration::ZERO);
+        assert_eq!(
+            wait_time(Some(now - Duration::from_secs(2)), now, interval),
+            Duration::from_secs(3)
+        );
+        assert_eq!(
+            wait_time(Some(now - Duration::from_secs(60)), now, interval),
+            Duration::ZERO
+        );
+        assert_eq!(
+            wait_time(Some(now + Duration::from_secs(60)), now, interval),
+            Duration::ZERO
+        );
+    }
+}
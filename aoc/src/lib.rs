@@ -0,0 +1,19 @@
+//! Runner driving every day of the workspace through a common interface.
+
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod fetch;
+pub mod output;
+pub mod pool;
+pub mod submit;
+
+use std::path::Path;
+
+/// The workspace directory, which the paths of the runner are relative to, wherever it
+/// is started from.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The runner lives in the workspace")
+}
@@ -0,0 +1,368 @@
+use std::{
+    fs,
+    fs::File,
+    io::{self, BufRead, BufReader, IsTerminal},
+    process::ExitCode,
+    thread,
+    time::Instant,
+};
+
+use aoc::{
+    bench::{self, Baseline, Phase, Stats},
+    client::Curl,
+    days::{self, Day, Solution},
+    fetch::{self, Fetched, Fetcher},
+    output::{self, DayRun, Format},
+    pool,
+    submit::{self, History, Verdict},
+};
+use cli::{BenchArgs, Command, RunAllArgs, RunArgs};
+
+mod cli;
+
+fn run(args: RunArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let day = days::find(args.day).ok_or_else(|| format!("Day {} isn't solved yet", args.day))?;
+
+    let mut reader: Box<dyn BufRead> = match &args.input {
+        _ if args.example => Box::new(day.example.input.as_bytes()),
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None if !io::stdin().is_terminal() => Box::new(io::stdin().lock()),
+        None => {
+            let path = day.input_path();
+            let file = File::open(&path).map_err(|e| {
+                format!(
+                    "{}: {e}, pipe the input or run `aoc fetch {}`",
+                    path.display(),
+                    day.number
+                )
+            })?;
+            Box::new(BufReader::new(file))
+        }
+    };
+
+    if let Some(k) = args.top {
+        let top = if args.stream {
+            d01::stream_top(reader, k)?
+        } else {
+            let content = aoc_common::read_input_from(reader)?;
+            let elves = d01::parse_elves(&content).map_err(|e| e.diagnostic(&content))?;
+            d01::top_elves(elves, k)
+        };
+        println!("{}", output::elves(&top, args.format));
+        return Ok(());
+    }
+
+    if args.report {
+        let content = aoc_common::read_input_from(reader)?;
+        let report = day
+            .report(&content, args.format)
+            .ok_or_else(|| format!("Day {} has no report", day.number))?
+            .map_err(|e| e.diagnostic(&content))?;
+        println!("{report}");
+        return Ok(());
+    }
+
+    let parts = parts(args.part);
+
+    let solutions = if args.stream {
+        day.stream(&mut reader, &parts)
+            .ok_or_else(|| format!("Day {} can't be streamed", day.number))??
+    } else {
+        let content = aoc_common::read_input_from(reader)?;
+        day.solve(&content, &parts)
+            .map_err(|e| e.diagnostic(&content))?
+    };
+
+    for solution in &solutions {
+        println!("{}", output::format(solution, args.format));
+    }
+
+    if args.example {
+        let mismatches = check_example(day, &solutions);
+        if mismatches > 0 {
+            return Err(format!("{mismatches} answer(s) differ from the example's").into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Parts to solve, both by default.
+fn parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+/// Points out the answers which aren't the example's, returning how many there are.
+fn check_example(day: &Day, solutions: &[Solution]) -> usize {
+    solutions
+        .iter()
+        .filter(|solution| {
+            let expected = day.example.answer(solution.part);
+            let wrong = solution.answer.to_string() != expected;
+            if wrong {
+                eprintln!(
+                    "Day {}, part {} should be: {expected}",
+                    solution.day, solution.part
+                );
+            }
+            wrong
+        })
+        .count()
+}
+
+/// Solves `parts` of `day` from its example or its input, errors being formatted for display.
+fn solve_from_file(day: &Day, parts: &[u8], example: bool) -> Result<Vec<Solution>, String> {
+    let content = if example {
+        day.example.input.to_string()
+    } else {
+        let path = day.input_path();
+        let file = File::open(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        aoc_common::read_input_from(file).map_err(|e| e.to_string())?
+    };
+
+    day.solve(&content, parts)
+        .map_err(|e| e.diagnostic(&content))
+}
+
+fn run_all(args: RunAllArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut selected = Vec::new();
+    for day in days::DAYS {
+        if !args.example && !day.input_path().exists() {
+            eprintln!(
+                "Skipping day {}: no input at {}",
+                day.number,
+                day.input_path().display()
+            );
+            continue;
+        }
+        selected.push(day);
+    }
+
+    let threads = args
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+    let parts = parts(args.part);
+
+    let start = Instant::now();
+    let runs = pool::map(&selected, threads, |day| DayRun {
+        day: day.number,
+        result: solve_from_file(day, &parts, args.example),
+    });
+    let wall = start.elapsed();
+
+    match args.format {
+        Format::Text => println!("{}", output::summary(&runs, wall, threads)),
+        Format::Json => {
+            for run in &runs {
+                match &run.result {
+                    Ok(solutions) => {
+                        for solution in solutions {
+                            println!("{}", output::format(solution, Format::Json));
+                        }
+                    }
+                    Err(e) => eprintln!("Day {}: {e}", run.day),
+                }
+            }
+        }
+    }
+
+    let failures = runs.iter().filter(|run| run.result.is_err()).count();
+    let mismatches: usize = match args.example {
+        true => selected
+            .iter()
+            .zip(&runs)
+            .filter_map(|(day, run)| Some(check_example(day, run.result.as_ref().ok()?)))
+            .sum(),
+        false => 0,
+    };
+
+    if failures > 0 || mismatches > 0 {
+        return Err(format!(
+            "{failures} day(s) failed, {mismatches} answer(s) differ from the examples'"
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Client of the website, authenticated by the session in the environment.
+fn fetcher() -> Result<Fetcher<Curl>, String> {
+    let session = std::env::var(fetch::SESSION_VAR).map_err(|_| {
+        format!(
+            "Set ${} to the session cookie of the website",
+            fetch::SESSION_VAR
+        )
+    })?;
+
+    Ok(Fetcher::new(
+        Curl,
+        session.trim(),
+        aoc::workspace_root().join(fetch::CACHE_DIR),
+    ))
+}
+
+fn fetch(day: u8) -> Result<(), Box<dyn std::error::Error>> {
+    match fetcher()?.input(day)? {
+        Fetched::Cached(path) => println!("Already cached at {}", path.display()),
+        Fetched::Downloaded(path) => println!("Downloaded to {}", path.display()),
+    }
+
+    Ok(())
+}
+
+fn submit(number: u8, part: u8) -> Result<(), Box<dyn std::error::Error>> {
+    let day = days::find(number).ok_or_else(|| format!("Day {number} isn't solved yet"))?;
+    let fetcher = fetcher()?;
+
+    let path = fetcher.input(number)?.path().to_owned();
+    let content = aoc_common::read_input_from(File::open(&path)?)?;
+    let solution = day
+        .solve(&content, &[part])
+        .map_err(|e| e.diagnostic(&content))?
+        .remove(0);
+    let answer = solution.answer.to_string();
+    println!("Day {number}, part {part}: {answer}");
+
+    let history_path = aoc::workspace_root().join(submit::HISTORY_PATH);
+    let mut history = match fs::read_to_string(&history_path) {
+        Ok(content) => History::parse(&content)?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => History::default(),
+        Err(e) => return Err(format!("{}: {e}", history_path.display()).into()),
+    };
+
+    let verdict = submit::submit(&fetcher, &mut history, number, part, &answer)?;
+    fs::write(&history_path, history.to_string())?;
+    println!("{verdict}");
+    match verdict {
+        Verdict::Correct => Ok(()),
+        _ => Err("The answer wasn't accepted".into()),
+    }
+}
+
+fn bench(args: BenchArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let selected: Vec<_> = match args.day {
+        Some(number) => {
+            vec![days::find(number).ok_or_else(|| format!("Day {number} isn't solved yet"))?]
+        }
+        None => days::DAYS.iter().collect(),
+    };
+
+    let mut baseline = match fs::read_to_string(&args.baseline) {
+        Ok(content) => Baseline::parse(&content)?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Baseline::default(),
+        Err(e) => return Err(e.into()),
+    };
+    let mut regressions = 0;
+
+    println!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}  vs baseline",
+        "Day", "Phase", "min", "median", "max"
+    );
+
+    for day in selected {
+        let path = match &args.input {
+            Some(path) => path.clone(),
+            None => day.input_path(),
+        };
+        let content = match File::open(&path) {
+            Ok(file) => aoc_common::read_input_from(file)?,
+            // Only complain about missing inputs if a day was explicitly requested
+            Err(e) if e.kind() == io::ErrorKind::NotFound && args.day.is_none() => {
+                eprintln!(
+                    "Skipping day {}: no input at {}",
+                    day.number,
+                    path.display()
+                );
+                continue;
+            }
+            Err(e) => return Err(format!("{}: {e}", path.display()).into()),
+        };
+
+        let timings = day
+            .bench(&content, args.iterations)
+            .map_err(|e| format!("Day {}: {}", day.number, e.diagnostic(&content)))?;
+
+        for phase in Phase::ALL {
+            let stats = Stats::new(timings.samples(phase)).expect("At least one iteration");
+
+            let comparison = match baseline.get(day.number, phase) {
+                Some(previous) => {
+                    let change = bench::change_percent(previous, stats.median);
+                    if change > args.threshold {
+                        regressions += 1;
+                        format!("{change:+.1}% REGRESSION")
+                    } else {
+                        format!("{change:+.1}%")
+                    }
+                }
+                None => "-".to_string(),
+            };
+
+            println!(
+                "{:>3}  {:<5}  {:>10.1?}  {:>10.1?}  {:>10.1?}  {comparison}",
+                day.number,
+                phase.name(),
+                stats.min,
+                stats.median,
+                stats.max
+            );
+
+            if args.save {
+                baseline.set(day.number, phase, stats.median);
+            }
+        }
+    }
+
+    // Saving the slower medians would hide the regressions from the next runs
+    if args.save && (regressions == 0 || args.force) {
+        fs::write(&args.baseline, baseline.to_string())?;
+        println!("Baseline written to {}", args.baseline.display());
+    }
+
+    if regressions > 0 {
+        let mut message = format!(
+            "{regressions} phase(s) more than {}% slower than the baseline",
+            args.threshold
+        );
+        if args.save && !args.force {
+            message += ", baseline not saved: use --force to save it anyway";
+        }
+        return Err(message.into());
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let command = match cli::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{e}\n\n{}", cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run(args) => run(args),
+        Command::RunAll(args) => run_all(args),
+        Command::Fetch(day) => fetch(day),
+        Command::Submit { day, part } => submit(day, part),
+        Command::Bench(args) => bench(args),
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::{fmt::Write, time::Duration};
+
+use aoc_common::Answer;
+pub use aoc_common::Format;
+use d01::Elf;
+
+use crate::days::Solution;
+
+/// Number of slowest parts pointed out by [`summary`].
+pub const SLOWEST: usize = 3;
+
+/// As JSON, one record per answer: `{"day":…,"part":…,"answer":…,"elapsed_ns":…}`.
+pub fn format(solution: &Solution, format: Format) -> String {
+    let Solution {
+        day,
+        part,
+        answer,
+        elapsed,
+    } = solution;
+
+    match format {
+        Format::Text => match answer {
+            Answer::Grid(_) => format!("Day {day}, part {part}:\n{answer}"),
+            _ => format!("Day {day}, part {part}: {answer}"),
+        },
+        Format::Json => format!(
+            r#"{{"day":{day},"part":{part},"answer":{},"elapsed_ns":{}}}"#,
+            answer.to_json(),
+            elapsed.as_nanos()
+        ),
+    }
+}
+
+/// What became of a day run along with others.
+#[derive(Clone, Debug)]
+pub struct DayRun {
+    pub day: u8,
+    /// The error is already formatted for display.
+    pub result: Result<Vec<Solution>, String>,
+}
+
+/// Table of the answers of every part of `runs`, sorted by day, with the slowest parts
+/// pointed out. Grid answers are printed below it.
+pub fn summary(runs: &[DayRun], wall: Duration, threads: usize) -> String {
+    let mut runs: Vec<_> = runs.iter().collect();
+    runs.sort_by_key(|r| r.day);
+
+    let solutions: Vec<_> = runs
+        .iter()
+        .filter_map(|r| r.result.as_ref().ok())
+        .flatten()
+        .collect();
+
+    let mut by_time = solutions.clone();
+    by_time.sort_by_key(|s| std::cmp::Reverse(s.elapsed));
+    let rank = |s: &Solution| {
+        by_time
+            .iter()
+            .take(SLOWEST)
+            .position(|slow| (slow.day, slow.part) == (s.day, s.part))
+    };
+
+    let cell = |answer: &Answer| match answer {
+        Answer::Grid(_) => "(see below)".to_string(),
+        _ => answer.to_string(),
+    };
+    let width = solutions
+        .iter()
+        .map(|s| cell(&s.answer).len())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut out = format!("Day  Part  {:<width$}  {:>10}\n", "Answer", "Time");
+    for run in runs {
+        match &run.result {
+            Ok(solutions) => {
+                for s in solutions {
+                    let marker = match rank(s) {
+                        Some(i) => format!("  <- slowest #{}", i + 1),
+                        None => String::new(),
+                    };
+                    writeln!(
+                        out,
+                        "{:>3}  {:>4}  {:<width$}  {:>10.1?}{marker}",
+                        s.day,
+                        s.part,
+                        cell(&s.answer),
+                        s.elapsed
+                    )
+                    .unwrap();
+                }
+            }
+            Err(e) => {
+                let first = e.lines().next().unwrap_or_default();
+                writeln!(out, "{:>3}  {:>4}  Error: {first}", run.day, "-").unwrap();
+            }
+        }
+    }
+    write!(out, "Total wall time: {wall:.1?} on {threads} thread(s)").unwrap();
+
+    for s in solutions {
+        if let Answer::Grid(_) = s.answer {
+            write!(out, "\n\n{}", format(s, Format::Text)).unwrap();
+        }
+    }
+
+    out
+}
+
+/// Report of day 1's `--top`, elves being numbered from 1.
+pub fn elves(elves: &[Elf], format: Format) -> String {
+    match format {
+        Format::Text => {
+            let mut out = format!(
+                "{:>4}  {:>9}  {:>5}  {:>8}",
+                "Rank", "Elf", "Items", "Calories"
+            );
+            for (rank, elf) in elves.iter().enumerate() {
+                write!(
+                    out,
+                    "\n{:>4}  {:>9}  {:>5}  {:>8}",
+                    rank + 1,
+                    elf.index + 1,
+                    elf.items,
+                    elf.total
+                )
+                .unwrap();
+            }
+            out
+        }
+        Format::Json => elves
+            .iter()
+            .enumerate()
+            .map(|(rank, elf)| {
+                format!(
+                    r#"{{"rank":{},"elf":{},"items":{},"calories":{}}}"#,
+                    rank + 1,
+                    elf.index + 1,
+                    elf.items,
+                    elf.total
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let solution = Solution {
+            day: 10,
+            part: 2,
+            answer: Answer::grid("#.\n.#"),
+            elapsed: Duration::from_nanos(1234),
+        };
+
+        assert_eq!(format(&solution, Format::Text), "Day 10, part 2:\n#.\n.#");
+        assert_eq!(
+            format(&solution, Format::Json),
+            r##"{"day":10,"part":2,"answer":["#.",".#"],"elapsed_ns":1234}"##
+        );
+    }
+
+    #[test]
+    fn test_elves() {
+        let elves = [
+            Elf {
+                index: 3,
+                total: 24000,
+                items: 3,
+            },
+            Elf {
+                index: 2,
+                total: 11000,
+                items: 2,
+            },
+        ];
+
+        assert_eq!(
+            super::elves(&elves, Format::Text),
+            "\
+Rank        Elf  Items  Calories
+   1          4      3     24000
+   2          3      2     11000"
+        );
+        assert_eq!(
+            super::elves(&elves[..1], Format::Json),
+            r#"{"rank":1,"elf":4,"items":3,"calories":24000}"#
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let solution = |day, part, answer, micros| Solution {
+            day,
+            part,
+            answer,
+            elapsed: Duration::from_micros(micros),
+        };
+        let runs = [
+            DayRun {
+                day: 10,
+                result: Ok(vec![
+                    solution(10, 1, 13140.into(), 5),
+                    solution(10, 2, Answer::grid("#.\n.#"), 40),
+                ]),
+            },
+            DayRun {
+                day: 3,
+                result: Err("invalid item\n --> line 2, column 1".to_string()),
+            },
+            DayRun {
+                day: 1,
+                result: Ok(vec![
+                    solution(1, 1, 24000.into(), 1),
+                    solution(1, 2, 45000.into(), 30),
+                ]),
+            },
+        ];
+
+        assert_eq!(
+            summary(&runs, Duration::from_millis(2), 4),
+            "\
+Day  Part  Answer             Time
+  1     1  24000             1.0µs
+  1     2  45000            30.0µs  <- slowest #2
+  3     -  Error: invalid item
+ 10     1  13140             5.0µs  <- slowest #3
+ 10     2  (see below)      40.0µs  <- slowest #1
+Total wall time: 2.0ms on 4 thread(s)
+
+Day 10, part 2:
+#.
+.#"
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+/// Applies `f` to every item on `threads` worker threads, results being in the order of `items`.
+///
+/// Each worker takes the next pending item as soon as it's done with the previous one,
+/// so that a slow item doesn't hold up the others.
+pub fn map<T: Sync, R: Send>(items: &[T], threads: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let threads = threads.clamp(1, items.len().max(1));
+
+    let mut results: Vec<(usize, R)> = thread::scope(|s| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(i) else {
+                            break done;
+                        };
+                        done.push((i, f(item)));
+                    }
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+            .collect()
+    });
+
+    results.sort_unstable_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, sync::Mutex, thread::ThreadId};
+
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let items: Vec<u64> = (0..100).collect();
+        let threads = Mutex::new(HashSet::<ThreadId>::new());
+
+        let squares = map(&items, 4, |&x| {
+            threads.lock().unwrap().insert(thread::current().id());
+            x * x
+        });
+
+        assert!(squares.iter().copied().eq((0..100).map(|x| x * x)));
+        assert!(threads.into_inner().unwrap().len() <= 4);
+    }
+
+    #[test]
+    fn test_map_empty() {
+        assert!(map(&[] as &[u8], 0, |&x| x).is_empty());
+    }
+}
@@ -0,0 +1,312 @@
+use std::{fmt, time::Duration};
+
+use crate::{client::Http, fetch::Fetcher};
+
+/// Where attempts are recorded, relative to the [workspace](crate::workspace_root).
+pub const HISTORY_PATH: &str = "inputs/history.txt";
+
+/// What the website made of an answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without any hint.
+    Wrong,
+    /// Not evaluated, as the previous answer was too recent.
+    Wait(Duration),
+    /// Not evaluated, most likely because the part is already solved.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Recognizes the verdict in the page answering a submission.
+    pub fn parse(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            Some(Verdict::Wait(parse_wait(page).unwrap_or_default()))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait(_) => "wait",
+            Verdict::WrongLevel => "wrong-level",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+            Verdict::Wait(Duration::ZERO),
+            Verdict::WrongLevel,
+        ]
+        .into_iter()
+        .find(|verdict| verdict.name() == name)
+    }
+
+    /// Whether the answer was evaluated and found wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::TooHigh => write!(f, "Wrong, too high"),
+            Verdict::TooLow => write!(f, "Wrong, too low"),
+            Verdict::Wrong => write!(f, "Wrong"),
+            Verdict::Wait(left) => write!(f, "Answered too recently, {left:?} left to wait"),
+            Verdict::WrongLevel => write!(f, "Not evaluated, is this part already solved?"),
+        }
+    }
+}
+
+/// Parses the "You have 1m 5s left to wait" of a page.
+fn parse_wait(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    page[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (n, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            match unit {
+                "s" => Some(n),
+                "m" => Some(n * 60),
+                "h" => Some(n * 3600),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// An answer submitted to a part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every attempt made so far, oldest first.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Parses lines of tab-separated day, part, verdict and answer.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let attempts = aoc_common::lines(content)
+            .map(|line| {
+                let invalid = || format!("Invalid history line: {line}");
+                let mut fields = line.splitn(4, '\t');
+                let mut next = || fields.next().ok_or_else(invalid);
+
+                Ok(Attempt {
+                    day: next()?.parse().map_err(|_| invalid())?,
+                    part: next()?.parse().map_err(|_| invalid())?,
+                    verdict: Verdict::from_name(next()?).ok_or_else(invalid)?,
+                    answer: next()?.to_string(),
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(History { attempts })
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Attempts at `part` of `day`, oldest first.
+    pub fn attempts(&self, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.day == day && a.part == part)
+    }
+
+    /// Bounds of the answer to a part, both excluded, inferred from the "too high"
+    /// and "too low" hints.
+    pub fn bounds(&self, day: u8, part: u8) -> (Option<i64>, Option<i64>) {
+        let mut low = None;
+        let mut high = None;
+
+        for attempt in self.attempts(day, part) {
+            let Ok(n) = attempt.answer.parse::<i64>() else {
+                continue;
+            };
+            match attempt.verdict {
+                Verdict::TooLow => low = low.max(Some(n)),
+                Verdict::TooHigh => high = Some(high.map_or(n, |h: i64| h.min(n))),
+                _ => {}
+            }
+        }
+
+        (low, high)
+    }
+
+    /// Why `answer` shouldn't be submitted, if the history tells it's wrong or pointless.
+    pub fn refusal(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+        for attempt in self.attempts(day, part) {
+            if attempt.verdict == Verdict::Correct {
+                return Some(format!(
+                    "Part {part} of day {day} is already solved, with {}",
+                    attempt.answer
+                ));
+            }
+            if attempt.verdict.is_wrong() && attempt.answer == answer {
+                return Some(format!("{answer} was already found wrong"));
+            }
+        }
+
+        let n: i64 = answer.parse().ok()?;
+        match self.bounds(day, part) {
+            (Some(low), _) if n <= low => Some(format!("{answer} is too low, {low} already was")),
+            (_, Some(high)) if n >= high => {
+                Some(format!("{answer} is too high, {high} already was"))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for a in &self.attempts {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}",
+                a.day,
+                a.part,
+                a.verdict.name(),
+                a.answer
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Submits `answer` unless `history` tells it's wrong, recording the attempt.
+pub fn submit<H: Http>(
+    fetcher: &Fetcher<H>,
+    history: &mut History,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict, String> {
+    if answer.contains('\n') {
+        return Err("Multi-line answers must be read and submitted by hand".to_string());
+    }
+    if let Some(reason) = history.refusal(day, part, answer) {
+        return Err(format!("Not submitting: {reason}"));
+    }
+
+    let response = fetcher.answer(day, part, answer)?;
+    if response.status != 200 {
+        return Err(format!("Unexpected status {}", response.status));
+    }
+    let verdict = Verdict::parse(&response.body).ok_or("Unexpected response from the website")?;
+
+    history.record(Attempt {
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+    });
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Fake;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data.</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(Verdict::parse(TOO_HIGH), Some(Verdict::TooHigh));
+        assert_eq!(
+            Verdict::parse(WAIT),
+            Some(Verdict::Wait(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Verdict::parse("<p>That's the right answer!  You are one gold star closer</p>"),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(Verdict::parse("<p>Log in</p>"), None);
+    }
+
+    #[test]
+    fn test_history() {
+        let content = "1\t1\ttoo-low\t100\n1\t1\ttoo-high\t200\n1\t1\ttoo-high\t180\n\
+            1\t1\twrong\t150\n1\t2\tcorrect\t7\n";
+        let history = History::parse(content).unwrap();
+        assert_eq!(history.to_string(), content);
+        assert_eq!(history.bounds(1, 1), (Some(100), Some(180)));
+
+        assert!(history.refusal(1, 1, "150").unwrap().contains("already"));
+        assert!(history.refusal(1, 1, "99").unwrap().contains("too low"));
+        assert!(history.refusal(1, 1, "190").unwrap().contains("too high"));
+        assert_eq!(history.refusal(1, 1, "160"), None);
+        assert!(history.refusal(1, 2, "8").unwrap().contains("solved"));
+        assert_eq!(history.refusal(2, 1, "150"), None);
+
+        assert!(History::parse("1\t1\tmaybe\t3").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let cache = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let fetcher = Fetcher::new(Fake::default(), "secret", cache)
+            .with_base_url("http://fake")
+            .with_min_interval(Duration::ZERO);
+        fetcher
+            .http()
+            .respond("http://fake/day/5/answer", 200, TOO_HIGH);
+        let mut history = History::default();
+
+        assert_eq!(
+            submit(&fetcher, &mut history, 5, 2, "42"),
+            Ok(Verdict::TooHigh)
+        );
+        assert_eq!(
+            fetcher.http().requests()[0].form,
+            [("level", "2"), ("answer", "42")].map(|(n, v)| (n.to_string(), v.to_string()))
+        );
+
+        // Refused without reaching the website, which has no response left
+        assert!(submit(&fetcher, &mut history, 5, 2, "42").is_err());
+        assert!(submit(&fetcher, &mut history, 5, 2, "50").is_err());
+        assert_eq!(fetcher.http().requests().len(), 1);
+        assert_eq!(history.attempts(5, 2).count(), 1);
+    }
+}
@@ -0,0 +1,148 @@
+//! Checks every day against its real puzzle input and the answers recorded in `answers.toml`.
+
+use std::{fs, path::Path};
+
+use aoc::{
+    days::DAYS,
+    fetch::{self, CACHE_DIR},
+};
+use aoc_common::Answer;
+use toml::{Table, Value};
+
+/// Line-by-line comparison, in the style of `diff -u` without context.
+fn diff(expected: &str, found: &str) -> String {
+    let expected: Vec<_> = expected.lines().collect();
+    let found: Vec<_> = found.lines().collect();
+
+    (0..expected.len().max(found.len()))
+        .map(|i| match (expected.get(i), found.get(i)) {
+            (Some(e), Some(f)) if e == f => format!("  {e}"),
+            (e, f) => [e.map(|e| format!("- {e}")), f.map(|f| format!("+ {f}"))]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join("\n"),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Compares an answer to its recorded value, returning a diff if they differ.
+fn check(expected: &Value, found: &Answer) -> Option<String> {
+    let expected = match expected {
+        Value::Integer(n) => n.to_string(),
+        Value::String(s) => s.trim_matches('\n').to_string(),
+        other => other.to_string(),
+    };
+    let found = found.to_string();
+
+    (expected != found).then(|| diff(&expected, &found))
+}
+
+/// Solves every day with an input in `root`'s `inputs/` and recorded answers in its
+/// `answers.toml`, returning a description of each mismatch.
+fn check_answers(root: &Path) -> Vec<String> {
+    let answers: Table = fs::read_to_string(root.join("answers.toml"))
+        .expect("Couldn't read answers.toml")
+        .parse()
+        .expect("Invalid answers.toml");
+
+    let mut failures = Vec::new();
+
+    for day in DAYS {
+        let key = format!("d{:02}", day.number);
+        let path = root.join(CACHE_DIR).join(fetch::input_name(day.number));
+
+        let Ok(content) = fs::read_to_string(&path) else {
+            eprintln!(
+                "Skipping day {}: no input at {}",
+                day.number,
+                path.display()
+            );
+            continue;
+        };
+        let Some(expected) = answers.get(&key).and_then(Value::as_table) else {
+            eprintln!("Skipping day {}: no recorded answers", day.number);
+            continue;
+        };
+
+        let parts: Vec<u8> = [1, 2]
+            .into_iter()
+            .filter(|part| expected.contains_key(&format!("part{part}")))
+            .collect();
+
+        let solutions = match day.solve(&aoc_common::normalize(&content), &parts) {
+            Ok(solutions) => solutions,
+            Err(e) => {
+                failures.push(format!("Day {}: {e}", day.number));
+                continue;
+            }
+        };
+
+        for solution in solutions {
+            let recorded = &expected[&format!("part{}", solution.part)];
+            if let Some(diff) = check(recorded, &solution.answer) {
+                failures.push(format!(
+                    "Day {}, part {}: answer changed\n{diff}",
+                    day.number, solution.part
+                ));
+            }
+        }
+    }
+
+    failures
+}
+
+#[test]
+fn recorded_answers() {
+    let failures = check_answers(aoc::workspace_root());
+    assert!(failures.is_empty(), "\n{}", failures.join("\n\n"));
+}
+
+#[test]
+fn test_check_answers() {
+    // The examples stand for real inputs
+    let root = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+    fs::create_dir_all(root.join(CACHE_DIR)).unwrap();
+    for day in [1, 10] {
+        let day = &DAYS[day - 1];
+        let path = root.join(CACHE_DIR).join(fetch::input_name(day.number));
+        fs::write(path, day.example.input).unwrap();
+    }
+    fs::write(
+        root.join("answers.toml"),
+        format!(
+            "[d01]\npart1 = 24000\npart2 = 45001\n\n[d05]\npart1 = \"CMZ\"\n\n[d10]\npart2 = \"\"\"\n{}\"\"\"\n",
+            DAYS[9].example.part2
+        ),
+    )
+    .unwrap();
+
+    let failures = check_answers(&root);
+    fs::remove_dir_all(&root).unwrap();
+    // Day 5 has no input, day 10 matches
+    assert_eq!(
+        failures,
+        ["Day 1, part 2: answer changed\n- 45001\n+ 45000"]
+    );
+}
+
+#[test]
+fn test_check() {
+    assert_eq!(check(&Value::Integer(42), &Answer::Int(42)), None);
+    assert_eq!(
+        check(&Value::Integer(42), &Answer::Int(43)),
+        Some("- 42\n+ 43".to_string())
+    );
+    assert_eq!(
+        check(
+            &Value::String("\n#.\n.#".to_string()),
+            &Answer::grid("#.\n##")
+        ),
+        Some("  #.\n- .#\n+ ##".to_string())
+    );
+    assert_eq!(
+        check(&Value::String("a\nb".to_string()), &Answer::from("a")),
+        Some("  a\n- b".to_string())
+    );
+}
@@ -0,0 +1,143 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    /// Integers above `i64::MAX`, others being `Int`.
+    UInt(u64),
+    Text(String),
+    /// ASCII art, one string per row.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Builds a grid answer from rendered text, ignoring empty lines.
+    pub fn grid(text: &str) -> Self {
+        Self::Grid(
+            text.lines()
+                .filter(|l| !l.is_empty())
+                .map(String::from)
+                .collect(),
+        )
+    }
+
+    /// Serializes the answer as a JSON value: a number, a string, or an array of rows.
+    pub fn to_json(&self) -> String {
+        match self {
+            Self::Int(n) => n.to_string(),
+            Self::UInt(n) => n.to_string(),
+            Self::Text(s) => json_string(s),
+            Self::Grid(rows) => {
+                let rows: Vec<_> = rows.iter().map(|r| json_string(r)).collect();
+                format!("[{}]", rows.join(","))
+            }
+        }
+    }
+}
+
+/// Quotes and escapes a string as per RFC 8259.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{n}"),
+            Self::UInt(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+            Self::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Self::Int(i64::from(n))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, i8, i16, i32, i64);
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        i64::try_from(n).map_or(Self::UInt(n), Self::Int)
+    }
+}
+
+/// Lossless, as `isize` is at most 64 bits wide.
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Self::Int(n as i64)
+    }
+}
+
+/// Lossless, as `usize` is at most 64 bits wide.
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::from(n as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(
+            Answer::grid("\n#..#\n.##.\n"),
+            Answer::Grid(vec!["#..#".to_string(), ".##.".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_from_int() {
+        assert_eq!(Answer::from(42u64), Answer::Int(42));
+        assert_eq!(Answer::from(u64::MAX), Answer::UInt(u64::MAX));
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(usize::MAX).to_json(), usize::MAX.to_string());
+        assert_eq!(Answer::from(-1isize), Answer::Int(-1));
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(Answer::Int(-42).to_json(), "-42");
+        assert_eq!(Answer::from("CMZ").to_json(), r#""CMZ""#);
+        assert_eq!(Answer::from("a\"b\\c\u{1}").to_json(), r#""a\"b\\c\u0001""#);
+        assert_eq!(Answer::grid("█.\n.█").to_json(), r#"["█.",".█"]"#);
+    }
+}
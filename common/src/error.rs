@@ -0,0 +1,235 @@
+use std::{fmt, io};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// Couldn't read the input.
+    Io(io::Error),
+    /// The input is malformed.
+    Parse(ParseError),
+    /// The input is well-formed, but has no answer.
+    NoSolution,
+}
+
+impl Error {
+    /// Describes the error, pointing at the offending text of `source` if relevant.
+    pub fn diagnostic(&self, source: &str) -> String {
+        match self {
+            Self::Parse(e) => e.diagnostic(source),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Parse(e) => Some(e),
+            Self::NoSolution => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+            Self::Parse(e) => write!(f, "Parse error: {e}"),
+            Self::NoSolution => write!(f, "No solution found"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// Malformed input, located in the text it was parsed from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Starting at 1.
+    pub line: usize,
+    /// Starting at 1, in characters.
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub message: String,
+    /// Byte offset of `text` in the source.
+    offset: usize,
+}
+
+/// Byte offset of `inner` in `outer`.
+/// Panics if `inner` isn't a subslice of `outer`.
+fn offset_in(outer: &str, inner: &str) -> usize {
+    let offset = (inner.as_ptr() as usize).wrapping_sub(outer.as_ptr() as usize);
+    assert!(
+        offset <= outer.len() && offset + inner.len() <= outer.len(),
+        "Text isn't part of the parsed source"
+    );
+    offset
+}
+
+impl ParseError {
+    /// Creates an error pointing at `span`, which must be a subslice of `source`.
+    /// An empty span at the end of `source` denotes unexpected end of input.
+    pub fn new(source: &str, span: &str, message: impl Into<String>) -> Self {
+        Self::at(source, offset_in(source, span), span, message.into())
+    }
+
+    /// Creates an error pointing at the end of `source`.
+    pub fn eof(source: &str, message: impl Into<String>) -> Self {
+        Self::new(source, &source[source.len()..], message)
+    }
+
+    fn at(source: &str, offset: usize, text: &str, message: String) -> Self {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            message,
+            offset,
+        }
+    }
+
+    /// Relocates an error created for `inner` within `outer`, of which `inner` must be a subslice.
+    pub fn within(self, outer: &str, inner: &str) -> Self {
+        let offset = offset_in(outer, inner) + self.offset;
+        Self::at(outer, offset, &self.text, self.message)
+    }
+
+    /// Relocates an error created for a single line, which is line `number` of the source.
+    pub fn on_line(mut self, number: usize) -> Self {
+        self.line += number - 1;
+        self
+    }
+
+    /// Describes the error in the style of a compiler, quoting the offending line of `source`.
+    ///
+    /// `source` must be the text the error was created or relocated for.
+    pub fn diagnostic(&self, source: &str) -> String {
+        let line = source.lines().nth(self.line - 1).unwrap_or("");
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        let width = self
+            .text
+            .lines()
+            .next()
+            .map_or(0, |t| t.chars().count())
+            .max(1);
+
+        format!(
+            "{}\n{gutter}--> line {}, column {}\n{gutter} |\n{number} | {line}\n{gutter} | {}{}",
+            self.message,
+            self.line,
+            self.column,
+            " ".repeat(self.column - 1),
+            "^".repeat(width),
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.message, self.line, self.column
+        )?;
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses each non-blank line of `content` with `f`, locating errors within `content`.
+pub fn parse_lines<T>(
+    content: &str,
+    mut f: impl FnMut(&str) -> std::result::Result<T, ParseError>,
+) -> std::result::Result<Vec<T>, ParseError> {
+    crate::lines(content)
+        .map(|line| f(line).map_err(|e| e.within(content, line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let source = "abc\ndéf ghi\n";
+        let e = ParseError::new(source, &source[9..12], "bad");
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 5, "ghi"));
+
+        let e = ParseError::eof(source, "missing");
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 1, ""));
+    }
+
+    #[test]
+    fn test_within() {
+        let source = "1\n2\n3 x";
+        let line = source.lines().nth(2).unwrap();
+        let e = ParseError::new(line, &line[2..], "bad").within(source, line);
+        assert_eq!((e.line, e.column), (3, 3));
+    }
+
+    #[test]
+    fn test_on_line() {
+        let e = ParseError::new("3 x", &"3 x"[2..], "bad").on_line(7);
+        assert_eq!((e.line, e.column), (7, 3));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let source = "1\n\n2\nx\n";
+        assert_eq!(
+            parse_lines(source, |l| l.parse::<u32>().map_err(|_| ParseError::new(
+                l,
+                l,
+                "not a number"
+            ))),
+            Err(ParseError::new(source, &source[5..6], "not a number"))
+        );
+        assert_eq!(
+            parse_lines("1\n\n2", |l| l.parse::<u32>().map_err(|_| ParseError::new(
+                l,
+                l,
+                "not a number"
+            ))),
+            Ok(vec![1, 2])
+        );
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let source = "addx 3\naddx 1x\n";
+        let e = ParseError::new(source, &source[12..14], "invalid integer");
+        assert_eq!(e.to_string(), "invalid integer at line 2, column 6: \"1x\"");
+        assert_eq!(
+            e.diagnostic(source),
+            "\
+invalid integer
+ --> line 2, column 6
+  |
+2 | addx 1x
+  |      ^^"
+        );
+    }
+}
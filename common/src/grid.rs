@@ -0,0 +1,336 @@
+use std::ops;
+
+use crate::ParseError;
+
+/// A position or a displacement on a grid, `y` growing downwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// Positions are displacements from the origin.
+pub type Point = Vec2;
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+    pub const UP: Vec2 = Vec2::new(0, -1);
+    pub const DOWN: Vec2 = Vec2::new(0, 1);
+    pub const LEFT: Vec2 = Vec2::new(-1, 0);
+    pub const RIGHT: Vec2 = Vec2::new(1, 0);
+
+    /// Unit steps to the 4 orthogonal neighbors, clockwise from up.
+    pub const ORTHOGONAL: [Vec2; 4] = [Vec2::UP, Vec2::RIGHT, Vec2::DOWN, Vec2::LEFT];
+
+    /// Unit steps to the 8 neighbors, diagonals included, clockwise from up.
+    pub const ADJACENT: [Vec2; 8] = [
+        Vec2::new(0, -1),
+        Vec2::new(1, -1),
+        Vec2::new(1, 0),
+        Vec2::new(1, 1),
+        Vec2::new(0, 1),
+        Vec2::new(-1, 1),
+        Vec2::new(-1, 0),
+        Vec2::new(-1, -1),
+    ];
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Sign of each coordinate: a unit step towards `self`, diagonals included.
+    pub fn signum(self) -> Self {
+        Vec2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Length with orthogonal steps only.
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Length with diagonal steps allowed.
+    pub fn chebyshev(self) -> usize {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+}
+
+impl ops::Add for Vec2 {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl ops::Sub for Vec2 {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl ops::Mul<isize> for Vec2 {
+    type Output = Self;
+    fn mul(self, rhs: isize) -> Self {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl ops::Neg for Vec2 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl ops::AddAssign for Vec2 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl ops::SubAssign for Vec2 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// A rectangle of cells, stored row by row, the top-left one being at the origin.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Returns `None` if the rows don't all have the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a map with one character per cell, skipping blank lines.
+    /// `cell` returns `None` for characters which aren't allowed, reported with `expected`.
+    pub fn parse(
+        content: &str,
+        expected: &str,
+        mut cell: impl FnMut(Point, char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (y, line) in crate::lines(content).enumerate() {
+            let line = line.trim_end();
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                let value = cell(Point::new(x as isize, y as isize), c).ok_or_else(|| {
+                    ParseError::new(content, &line[i..i + c.len_utf8()], expected)
+                })?;
+                cells.push(value);
+            }
+
+            let line_width = line.chars().count();
+            if *width.get_or_insert(line_width) != line_width {
+                return Err(ParseError::new(
+                    content,
+                    line,
+                    "rows must have the same width",
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.ok_or_else(|| ParseError::eof(content, "expected a map"))?,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (0..self.width as isize).contains(&p.x) && (0..self.height as isize).contains(&p.y)
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// Panics if `y` is out of bounds.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Cells of column `x`, from top to bottom.
+    /// Panics if `x` is out of bounds.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "Column out of bounds");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // chunks_exact panics on a zero chunk size, which an empty grid has
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// Every position, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// Orthogonal neighbors of `p` within the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Vec2::ORTHOGONAL
+            .into_iter()
+            .map(move |d| p + d)
+            .filter(|&n| self.contains(n))
+    }
+
+    /// Neighbors of `p` within the grid, diagonals included.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Vec2::ADJACENT
+            .into_iter()
+            .map(move |d| p + d)
+            .filter(|&n| self.contains(n))
+    }
+
+    /// Cells from `p` excluded in direction `step`, up to the edge of the grid.
+    pub fn ray(&self, p: Point, step: Vec2) -> impl Iterator<Item = (Point, &T)> {
+        std::iter::successors(Some(p + step), move |&n| Some(n + step))
+            .map_while(|n| Some((n, self.get(n)?)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> ops::Index<Point> for Grid<T> {
+    type Output = T;
+
+    /// Panics if `p` is out of bounds.
+    fn index(&self, p: Point) -> &T {
+        self.get(p).expect("Point out of bounds")
+    }
+}
+
+impl<T> ops::IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        self.get_mut(p).expect("Point out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456\n", "expected a digit", |_, c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_vec2() {
+        let v = Vec2::new(3, -4);
+        assert_eq!(v + Vec2::UP, Vec2::new(3, -5));
+        assert_eq!(v - Vec2::new(1, 1), Vec2::new(2, -5));
+        assert_eq!(-v * 2, Vec2::new(-6, 8));
+        assert_eq!(v.signum(), Vec2::new(1, -1));
+        assert_eq!((v.manhattan(), v.chebyshev()), (7, 4));
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(
+            Some(grid),
+            Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])
+        );
+
+        let e = Grid::parse("12\n3x", "expected a digit", |_, c| c.to_digit(10)).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "x"));
+
+        let e = Grid::parse("12\n345", "expected a digit", |_, c| c.to_digit(10)).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "345"));
+
+        assert!(Grid::parse("\n", "", |_, c| Some(c)).is_err());
+    }
+
+    #[test]
+    fn test_slices() {
+        let grid = digits();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert!(grid.column(1).eq(&[2, 5]));
+        assert!(grid.column(2).rev().eq(&[6, 3]));
+        assert!(grid.rows().eq([[1, 2, 3], [4, 5, 6]].iter()));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = digits();
+        assert!(grid
+            .neighbors4(Point::new(0, 0))
+            .eq([Point::new(1, 0), Point::new(0, 1)]));
+        assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = digits();
+        assert!(grid
+            .ray(Point::new(0, 1), Vec2::RIGHT)
+            .map(|(_, &d)| d)
+            .eq([5, 6]));
+        assert_eq!(grid.ray(Point::new(0, 1), Vec2::LEFT).count(), 0);
+    }
+}
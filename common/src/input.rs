@@ -0,0 +1,120 @@
+use std::io::{self, Read};
+
+use crate::Result;
+
+/// Reads the whole puzzle input from stdin, with line endings normalized to `\n`.
+pub fn read_input() -> Result<String> {
+    read_input_from(io::stdin())
+}
+
+/// Reads the whole puzzle input from `reader`, with line endings normalized to `\n`.
+pub fn read_input_from(reader: impl Read) -> Result<String> {
+    Ok(normalize(&io::read_to_string(reader)?))
+}
+
+/// Replaces Windows line endings by `\n`.
+pub fn normalize(content: &str) -> String {
+    // Bleeping Windows
+    content.replace("\r\n", "\n")
+}
+
+/// Iterates over the non-blank lines of `content`.
+/// Handles both `\n` and `\r\n` line endings.
+pub fn lines(content: &str) -> impl Iterator<Item = &str> {
+    content.lines().filter(|l| !l.trim().is_empty())
+}
+
+/// Splits `content` into blocks separated by one or more blank lines.
+/// Handles both `\n` and `\r\n` line endings. Leading and trailing blank lines are ignored.
+/// # Examples
+/// ```
+/// let blocks: Vec<_> = aoc_common::blocks("1\r\n2\r\n\r\n3\n").collect();
+/// assert_eq!(blocks, ["1\r\n2", "3"]);
+/// ```
+pub fn blocks(content: &str) -> Blocks<'_> {
+    Blocks { rest: content }
+}
+
+/// Iterator returned by [`blocks`].
+#[derive(Clone, Debug)]
+pub struct Blocks<'a> {
+    rest: &'a str,
+}
+
+/// Splits off the first line, newline excluded.
+fn split_line(s: &str) -> (&str, &str) {
+    match s.find('\n') {
+        Some(i) => (&s[..i], &s[i + 1..]),
+        None => (s, ""),
+    }
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        // Skip blank lines
+        loop {
+            if self.rest.is_empty() {
+                return None;
+            }
+            let (line, rest) = split_line(self.rest);
+            if !line.trim().is_empty() {
+                break;
+            }
+            self.rest = rest;
+        }
+
+        let start = self.rest;
+        while !self.rest.is_empty() {
+            let (line, rest) = split_line(self.rest);
+            if line.trim().is_empty() {
+                break;
+            }
+            self.rest = rest;
+        }
+
+        let len = start.len() - self.rest.len();
+        Some(start[..len].trim_end_matches(['\r', '\n']))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a\r\nb\n\r\nc"), "a\nb\n\nc");
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(
+            lines("a\r\n\r\nb\n  \nc").collect::<Vec<_>>(),
+            ["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        assert_eq!(
+            blocks("1\n2\n\n3\n\n\n\n4\n5\n").collect::<Vec<_>>(),
+            ["1\n2", "3", "4\n5"]
+        );
+        assert_eq!(
+            blocks("\r\n1\r\n2\r\n\r\n3").collect::<Vec<_>>(),
+            ["1\r\n2", "3"]
+        );
+        assert_eq!(blocks("").count(), 0);
+        assert_eq!(blocks("\n\n \n").count(), 0);
+    }
+
+    #[test]
+    fn test_blocks_keep_indentation() {
+        assert_eq!(
+            blocks("    [D]\n[N] [C]\n\nmove 1").collect::<Vec<_>>(),
+            ["    [D]\n[N] [C]", "move 1"]
+        );
+    }
+}
@@ -0,0 +1,20 @@
+//! Helpers shared by every day of the workspace.
+
+mod answer;
+mod error;
+mod grid;
+mod input;
+mod report;
+mod solver;
+mod stream;
+
+pub mod random;
+pub mod search;
+
+pub use answer::Answer;
+pub use error::{parse_lines, Error, ParseError, Result};
+pub use grid::{Grid, Point, Vec2};
+pub use input::{blocks, lines, normalize, read_input, read_input_from, Blocks};
+pub use report::{Format, Reporting};
+pub use solver::{check_example, run, Example, Solver};
+pub use stream::{check_stream_example, feed_lines, feed_lines_into, Streaming};
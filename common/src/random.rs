@@ -0,0 +1,37 @@
+//! Deterministic pseudo-random numbers, for tests and benchmarks to generate inputs.
+
+/// A xorshift generator, always seeded the same so that runs can be reproduced.
+#[derive(Clone, Debug)]
+pub struct XorShift(u64);
+
+impl Default for XorShift {
+    fn default() -> Self {
+        XorShift(0x2545_f491_4f6c_dd1d)
+    }
+}
+
+impl XorShift {
+    /// A number less than `n`, which must not be 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_below() {
+        let mut random = XorShift::default();
+        let numbers: Vec<_> = (0..1000).map(|_| random.below(10)).collect();
+        assert!(numbers.iter().all(|&n| n < 10));
+        assert!((0..10).all(|n| numbers.contains(&n)));
+
+        let mut again = XorShift::default();
+        assert!(numbers.iter().all(|&n| again.below(10) == n));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::Result;
+
+/// How answers and reports are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable lines.
+    #[default]
+    Text,
+    /// One JSON record per line.
+    Json,
+}
+
+impl Format {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// A day which can tell more about its input than the answers, as `aoc run <day> --report`.
+pub trait Reporting {
+    /// Parses `input` and describes it, errors being located within `input`.
+    fn report(input: &str, format: Format) -> Result<String>;
+}
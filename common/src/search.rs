@@ -0,0 +1,276 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cheapest way found to a goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    /// From one of the starts to the goal, both included.
+    pub nodes: Vec<N>,
+}
+
+impl<N, C> Path<N, C> {
+    pub fn start(&self) -> &N {
+        self.nodes.first().expect("A path has at least one node")
+    }
+
+    pub fn goal(&self) -> &N {
+        self.nodes.last().expect("A path has at least one node")
+    }
+}
+
+struct Visit<N, C> {
+    node: N,
+    parent: Option<usize>,
+    cost: C,
+}
+
+/// Every node reached so far, along with the cheapest way there.
+struct Tree<N, C> {
+    visits: Vec<Visit<N, C>>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy + Ord> Tree<N, C> {
+    fn new() -> Self {
+        Tree {
+            visits: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Records a way to `node`, returning its index if it's the first or cheapest one.
+    fn relax(&mut self, node: N, parent: Option<usize>, cost: C) -> Option<usize> {
+        match self.index.get(&node) {
+            Some(&i) if self.visits[i].cost <= cost => None,
+            Some(&i) => {
+                self.visits[i].parent = parent;
+                self.visits[i].cost = cost;
+                Some(i)
+            }
+            None => {
+                let i = self.visits.len();
+                self.index.insert(node.clone(), i);
+                self.visits.push(Visit { node, parent, cost });
+                Some(i)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<N, C> {
+        let mut nodes: Vec<_> = std::iter::successors(Some(goal), |&i| self.visits[i].parent)
+            .map(|i| self.visits[i].node.clone())
+            .collect();
+        nodes.reverse();
+
+        Path {
+            cost: self.visits[goal].cost,
+            nodes,
+        }
+    }
+}
+
+/// Finds a path with the fewest steps from any of `starts` to the first node satisfying `is_goal`.
+///
+/// `neighbors` yields the nodes one step away from a node.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = Tree::new();
+    let mut queue: VecDeque<_> = starts
+        .into_iter()
+        .filter_map(|start| tree.relax(start, None, 0))
+        .collect();
+
+    while let Some(i) = queue.pop_front() {
+        let Visit { node, cost, .. } = &tree.visits[i];
+        let (node, cost) = (node.clone(), *cost);
+        if is_goal(&node) {
+            return Some(tree.path(i));
+        }
+
+        // Nodes are reached in order of cost, so the first way to a node is the cheapest
+        queue.extend(
+            neighbors(&node)
+                .into_iter()
+                .filter_map(|next| tree.relax(next, Some(i), cost + 1)),
+        );
+    }
+
+    None
+}
+
+/// Finds a cheapest path from any of `starts` to the first node satisfying `is_goal`.
+///
+/// `neighbors` yields the nodes one step away from a node, along with the cost of that step.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// Finds a cheapest path from any of `starts` to the first node satisfying `is_goal`,
+/// exploring the nodes which `heuristic` deems closest to a goal first.
+///
+/// `neighbors` yields the nodes one step away from a node, along with the cost of that step.
+/// The path is only guaranteed to be the cheapest if `heuristic` never overestimates the cost
+/// to a goal.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree = Tree::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(i) = tree.relax(start, None, C::default()) {
+            heap.push(Reverse((estimate, C::default(), i)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        // Skip nodes reached again more cheaply since they were queued
+        if cost > tree.visits[i].cost {
+            continue;
+        }
+
+        let node = tree.visits[i].node.clone();
+        if is_goal(&node) {
+            return Some(tree.path(i));
+        }
+
+        for (next, step) in neighbors(&node) {
+            let estimate = heuristic(&next);
+            if let Some(j) = tree.relax(next, Some(i), cost + step) {
+                heap.push(Reverse((cost + step + estimate, cost + step, j)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point};
+
+    /// `#` are walls, digits the cost of entering a tile.
+    fn maze() -> Grid<Option<u32>> {
+        Grid::parse(
+            "\
+S1111
+9###1
+1119E",
+            "",
+            |_, c| match c {
+                '#' => Some(None),
+                'S' | 'E' => Some(Some(1)),
+                _ => Some(c.to_digit(10)),
+            },
+        )
+        .unwrap()
+    }
+
+    const START: Point = Point::new(0, 0);
+    const END: Point = Point::new(4, 2);
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let path = bfs(
+            [START],
+            |&p| maze.neighbors4(p).filter(|&n| maze[n].is_some()),
+            |&p| p == END,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.nodes.len(), 7);
+        assert_eq!((*path.start(), *path.goal()), (START, END));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let maze = maze();
+        let path = dijkstra(
+            [START],
+            |&p| maze.neighbors4(p).filter_map(|n| Some((n, maze[n]?))),
+            |&p| p == END,
+        )
+        .unwrap();
+
+        // Around the top, avoiding both 9s
+        assert_eq!(path.cost, 6);
+        assert_eq!(
+            path.nodes,
+            [(0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (4, 1), (4, 2)].map(|(x, y)| Point::new(x, y))
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let maze = maze();
+        let path = astar(
+            [START],
+            |&p| maze.neighbors4(p).filter_map(|n| Some((n, maze[n]?))),
+            |&p| (END - p).manhattan() as u32,
+            |&p| p == END,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 6);
+    }
+
+    #[test]
+    fn test_multi_source() {
+        let maze = maze();
+        let path = bfs(
+            [START, Point::new(0, 2)],
+            |&p| maze.neighbors4(p).filter(|&n| maze[n].is_some()),
+            |&p| p == Point::new(2, 2),
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 2);
+        assert_eq!(*path.start(), Point::new(0, 2));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let maze = maze();
+        assert_eq!(
+            bfs([START], |&p| maze.neighbors4(p), |&p| p == Point::new(9, 9)),
+            None
+        );
+        assert_eq!(
+            dijkstra([START], |_| [(START, 1)], |&p| p == END),
+            None::<Path<Point, u32>>
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use std::process::ExitCode;
+
+use crate::{Answer, Result};
+
+/// A day of the puzzle calendar.
+///
+/// The input is parsed exactly once, and both parts are solved from the parsed result.
+pub trait Solver {
+    /// Day of the calendar, starting at 1.
+    const DAY: u8;
+
+    /// The example of the puzzle statement.
+    const EXAMPLE: Example;
+
+    /// Parsed puzzle input, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+}
+
+/// The example input given in a puzzle statement, and its answers as displayed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: &'static str,
+    pub part1: &'static str,
+    pub part2: &'static str,
+}
+
+impl Example {
+    /// Expected answer to `part` (1 or 2).
+    pub fn answer(&self, part: u8) -> &'static str {
+        match part {
+            1 => self.part1,
+            _ => self.part2,
+        }
+    }
+}
+
+/// Solves the example of `S`, panicking if either answer isn't the expected one.
+///
+/// Meant for unit tests.
+#[track_caller]
+pub fn check_example<S: Solver>() {
+    let example = S::EXAMPLE;
+    let input = match S::parse(example.input) {
+        Ok(input) => input,
+        Err(e) => panic!("Invalid example: {}", e.diagnostic(example.input)),
+    };
+
+    for (part, answer) in [(1, S::part1(&input)), (2, S::part2(&input))] {
+        match answer {
+            Ok(answer) => assert_eq!(
+                answer.to_string(),
+                example.answer(part),
+                "Wrong answer to part {part} of the example"
+            ),
+            Err(e) => panic!("Part {part} of the example failed: {e}"),
+        }
+    }
+}
+
+fn solve<S: Solver>(content: &str) -> Result<()> {
+    let input = S::parse(content)?;
+
+    for (part, answer) in [(1, S::part1(&input)?), (2, S::part2(&input)?)] {
+        match answer {
+            Answer::Grid(_) => println!("Part {part}:\n{answer}"),
+            _ => println!("Part {part}: {answer}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Entry point of the per-day binaries: solves both parts from stdin.
+pub fn run<S: Solver>() -> ExitCode {
+    let content = match crate::read_input() {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match solve::<S>(&content) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e.diagnostic(&content));
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use std::io::BufRead;
+
+use crate::{Answer, ParseError, Result, Solver};
+
+/// A day whose input can be processed one line at a time, without holding it in memory.
+///
+/// This is an alternative to [`Solver::parse`] for huge inputs, both parts being updated
+/// as lines come in.
+pub trait Streaming {
+    /// What is kept of the lines fed so far.
+    type State: Default;
+
+    /// Processes the next line, newline excluded. Blank lines are fed too.
+    ///
+    /// Errors are located within `line`.
+    fn feed(state: &mut Self::State, line: &str) -> std::result::Result<(), ParseError>;
+
+    fn part1(state: &Self::State) -> Result<Answer>;
+    fn part2(state: &Self::State) -> Result<Answer>;
+}
+
+/// Feeds every line of `reader` to `S`, reusing a single line buffer.
+///
+/// Parse errors are located by line number only, as the input isn't kept.
+pub fn feed_lines<S: Streaming>(reader: impl BufRead) -> Result<S::State> {
+    feed_lines_into::<S>(reader, S::State::default())
+}
+
+/// Feeds every line of `reader` to `S` as [`feed_lines`] does, starting from `state`
+/// rather than the default one.
+pub fn feed_lines_into<S: Streaming>(
+    mut reader: impl BufRead,
+    mut state: S::State,
+) -> Result<S::State> {
+    let mut buf = String::new();
+
+    for number in 1.. {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            break;
+        }
+        let line = buf.trim_end_matches(['\n', '\r']);
+        S::feed(&mut state, line).map_err(|e| e.on_line(number))?;
+    }
+
+    Ok(state)
+}
+
+/// Streams the example of `S`, panicking if either answer isn't the expected one.
+///
+/// Meant for unit tests, along with [`check_example`](crate::check_example).
+#[track_caller]
+pub fn check_stream_example<S: Solver + Streaming>() {
+    let example = S::EXAMPLE;
+    let state = match feed_lines::<S>(example.input.as_bytes()) {
+        Ok(state) => state,
+        Err(e) => panic!("Invalid example: {e}"),
+    };
+
+    let answers = [
+        (1, <S as Streaming>::part1(&state)),
+        (2, <S as Streaming>::part2(&state)),
+    ];
+    for (part, answer) in answers {
+        match answer {
+            Ok(answer) => assert_eq!(
+                answer.to_string(),
+                example.answer(part),
+                "Wrong answer to part {part} of the streamed example"
+            ),
+            Err(e) => panic!("Part {part} of the streamed example failed: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts non-blank lines, rejecting anything but digits.
+    struct Digits;
+
+    impl Streaming for Digits {
+        type State = u32;
+
+        fn feed(count: &mut u32, line: &str) -> std::result::Result<(), ParseError> {
+            if let Some(i) = line.find(|c: char| !c.is_ascii_digit()) {
+                return Err(ParseError::new(line, &line[i..=i], "not a digit"));
+            }
+            *count += !line.is_empty() as u32;
+            Ok(())
+        }
+
+        fn part1(count: &u32) -> Result<Answer> {
+            Ok((*count).into())
+        }
+
+        fn part2(count: &u32) -> Result<Answer> {
+            Ok((*count).into())
+        }
+    }
+
+    #[test]
+    fn test_feed_lines() {
+        let count = feed_lines::<Digits>("12\r\n\n34\n5".as_bytes()).unwrap();
+        assert_eq!(count, 3);
+
+        let e = feed_lines::<Digits>("12\r\n\n3x4\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Parse error: not a digit at line 3, column 2: \"x\""
+        );
+    }
+}
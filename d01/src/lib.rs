@@ -0,0 +1,322 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    io::BufRead,
+};
+
+use aoc_common::{Answer, Error, Example, ParseError, Result, Solver, Streaming};
+
+pub struct Day01;
+
+/// What an elf carries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Elf {
+    /// Position in the input, from 0.
+    pub index: usize,
+    /// Sum of counts of at most `u32::MAX` each, so that it doesn't overflow.
+    pub total: u64,
+    pub items: usize,
+}
+
+/// Sums the calories carried by each elf, in input order.
+pub fn parse_elves(content: &str) -> std::result::Result<Vec<Elf>, ParseError> {
+    aoc_common::blocks(content)
+        .enumerate()
+        .map(|(index, block)| {
+            let mut elf = Elf {
+                index,
+                total: 0,
+                items: 0,
+            };
+            for line in block.lines() {
+                let line = line.trim_end();
+                let calories = line
+                    .parse::<u32>()
+                    .map_err(|_| ParseError::new(content, line, "invalid calories count"))?;
+                elf.total += u64::from(calories);
+                elf.items += 1;
+            }
+            Ok(elf)
+        })
+        .collect()
+}
+
+/// The `k` elves carrying the most calories, the most first, or every elf if there are
+/// fewer. Ties are broken by input order.
+///
+/// Only `k` elves are held at a time.
+pub fn top_elves(elves: impl IntoIterator<Item = Elf>, k: usize) -> Vec<Elf> {
+    let mut podium = Podium::new(k);
+    for elf in elves {
+        podium.push(elf);
+    }
+    podium.elves()
+}
+
+/// Reads an input line by line, returning the `k` elves carrying the most calories as
+/// [`top_elves`] does, without holding more than `k` elves.
+pub fn stream_top(reader: impl BufRead, k: usize) -> Result<Vec<Elf>> {
+    let totals = aoc_common::feed_lines_into::<Day01>(reader, Totals::new(k))?;
+    Ok(totals.top())
+}
+
+/// Orders elves by calories, the first elf winning ties.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Ranked(Elf);
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.0.total, Reverse(self.0.index)).cmp(&(other.0.total, Reverse(other.0.index)))
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The `k` best elves pushed so far.
+#[derive(Clone, Debug)]
+pub struct Podium {
+    k: usize,
+    /// Min-heap, the worst of the best elves on top.
+    heap: BinaryHeap<Reverse<Ranked>>,
+}
+
+impl Podium {
+    /// The heap grows with the elves pushed, so that `k` may be any number.
+    pub fn new(k: usize) -> Self {
+        Podium {
+            k,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, elf: Elf) {
+        self.heap.push(Reverse(Ranked(elf)));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The best elves, the most first.
+    pub fn elves(&self) -> Vec<Elf> {
+        // Sorting the min-heap increasingly puts the best elf first
+        self.heap
+            .clone()
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(Ranked(elf))| elf)
+            .collect()
+    }
+}
+
+/// Best elves of a streamed input.
+#[derive(Clone, Debug)]
+pub struct Totals {
+    /// The elf being read, if any.
+    current: Option<Elf>,
+    /// Number of elves read so far, the current one excluded.
+    count: usize,
+    podium: Podium,
+}
+
+impl Totals {
+    /// Keeps the `k` elves carrying the most, holding no more than were read.
+    pub fn new(k: usize) -> Self {
+        Totals {
+            current: None,
+            count: 0,
+            podium: Podium::new(k),
+        }
+    }
+
+    /// Best elves, the one being read included.
+    pub fn top(&self) -> Vec<Elf> {
+        let mut podium = self.podium.clone();
+        if let Some(current) = self.current {
+            podium.push(current);
+        }
+        podium.elves()
+    }
+}
+
+/// Keeps the 3 elves carrying the most, as part 2 needs.
+impl Default for Totals {
+    fn default() -> Self {
+        Totals::new(3)
+    }
+}
+
+impl Streaming for Day01 {
+    type State = Totals;
+
+    /// Elves are separated by any number of blank lines, trailing whitespace being ignored.
+    fn feed(totals: &mut Totals, line: &str) -> std::result::Result<(), ParseError> {
+        let line = line.trim_end();
+        if line.is_empty() {
+            if let Some(current) = totals.current.take() {
+                totals.podium.push(current);
+                totals.count += 1;
+            }
+            return Ok(());
+        }
+
+        let calories = line
+            .parse::<u32>()
+            .map_err(|_| ParseError::new(line, line, "invalid calories count"))?;
+        let elf = totals.current.get_or_insert(Elf {
+            index: totals.count,
+            total: 0,
+            items: 0,
+        });
+        elf.total += u64::from(calories);
+        elf.items += 1;
+        Ok(())
+    }
+
+    fn part1(totals: &Totals) -> Result<Answer> {
+        let max = totals.top().first().ok_or(Error::NoSolution)?.total;
+        Ok(max.into())
+    }
+
+    fn part2(totals: &Totals) -> Result<Answer> {
+        Ok(totals.top().iter().map(|elf| elf.total).sum::<u64>().into())
+    }
+}
+
+impl Solver for Day01 {
+    const DAY: u8 = 1;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+",
+        part1: "24000",
+        part2: "45000",
+    };
+
+    /// Every elf, in input order.
+    type Input = Vec<Elf>;
+
+    fn parse(input: &str) -> Result<Vec<Elf>> {
+        Ok(parse_elves(input)?)
+    }
+
+    fn part1(elves: &Vec<Elf>) -> Result<Answer> {
+        let top = top_elves(elves.iter().copied(), 1);
+        Ok(top.first().ok_or(Error::NoSolution)?.total.into())
+    }
+
+    fn part2(elves: &Vec<Elf>) -> Result<Answer> {
+        let top = top_elves(elves.iter().copied(), 3);
+        Ok(top.iter().map(|elf| elf.total).sum::<u64>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day01>();
+        aoc_common::check_stream_example::<Day01>();
+    }
+
+    #[test]
+    fn test_top_elves() {
+        let elves = parse_elves(Day01::EXAMPLE.input).unwrap();
+        let elf = |index, total, items| Elf {
+            index,
+            total,
+            items,
+        };
+
+        assert_eq!(
+            top_elves(elves.iter().copied(), 2),
+            [elf(3, 24000, 3), elf(2, 11000, 2)]
+        );
+        assert_eq!(top_elves(elves.iter().copied(), 10).len(), 5);
+        assert_eq!(top_elves(elves.iter().copied(), 0), []);
+        assert_eq!(top_elves(elves.iter().copied(), usize::MAX).len(), 5);
+
+        // Ties are broken by input order
+        let tied = [elf(0, 5, 1), elf(1, 7, 1), elf(2, 5, 2)];
+        assert_eq!(top_elves(tied, 2), [elf(1, 7, 1), elf(0, 5, 1)]);
+    }
+
+    #[test]
+    fn test_few_elves() {
+        let elves = parse_elves("100\n200\n\n300\n").unwrap();
+        assert_eq!(<Day01 as Solver>::part2(&elves).unwrap().to_string(), "600");
+        assert!(<Day01 as Solver>::part1(&Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_stream_top() {
+        let input = "1000\r\n2000 \r\n\r\n\r\n\r\n4000\n  \n\n5000\n6000\t\n";
+        let top = stream_top(input.as_bytes(), 5).unwrap();
+        assert_eq!(top, top_elves(parse_elves(input).unwrap(), 5));
+        assert_eq!(
+            top.iter()
+                .map(|elf| (elf.index, elf.items))
+                .collect::<Vec<_>>(),
+            [(2, 2), (1, 1), (0, 2)]
+        );
+    }
+
+    #[test]
+    fn test_stream_any_k() {
+        let mut totals = Totals::new(usize::MAX);
+        for line in ["100", "", "300", "", "200"] {
+            Day01::feed(&mut totals, line).unwrap();
+        }
+        let top: Vec<_> = totals.top().iter().map(|elf| elf.total).collect();
+        assert_eq!(top, [300, 200, 100]);
+
+        let top = stream_top("1\n\n2\n".as_bytes(), usize::MAX).unwrap();
+        assert_eq!(top.len(), 2);
+    }
+
+    #[test]
+    fn test_large_totals() {
+        let input = "4294967295\n4294967295\n\n4294967295\n\n4294967295\n";
+        let expected = (4 * u64::from(u32::MAX)).to_string();
+        let elves = Day01::parse(input).unwrap();
+        assert_eq!(elves[0].total, 2 * u64::from(u32::MAX));
+        assert_eq!(
+            <Day01 as Solver>::part2(&elves).unwrap().to_string(),
+            expected
+        );
+
+        let totals = aoc_common::feed_lines::<Day01>(input.as_bytes()).unwrap();
+        assert_eq!(
+            <Day01 as Streaming>::part2(&totals).unwrap().to_string(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let e = aoc_common::feed_lines::<Day01>("1\n\n2\nx3\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Parse error: invalid calories count at line 4, column 1: \"x3\""
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{Choice, Outcome, Round, Rules};
+
+/// A reading of the second column of the strategy guide.
+pub trait StrategyDecoder {
+    /// What we play in `round`, or `None` if the rules have no such choice.
+    fn response(&self, rules: &Rules, round: &Round) -> Option<Choice>;
+
+    /// Points scored by following the guide in `round`.
+    fn points(&self, rules: &Rules, round: &Round) -> Option<u32> {
+        Some(rules.score(self.response(rules, round)?, round.opponent))
+    }
+
+    /// Points scored by following the guide in every round, or `None` if there's no
+    /// response to one of them.
+    fn total<'a>(&self, rules: &Rules, rounds: impl IntoIterator<Item = &'a Round>) -> Option<u32>
+    where
+        Self: Sized,
+    {
+        rounds
+            .into_iter()
+            .map(|round| self.points(rules, round))
+            .sum()
+    }
+}
+
+/// Reads X, Y and Z as the first three choices of the rules (part 1).
+#[derive(Clone, Copy, Debug)]
+pub struct AsChoice;
+
+impl StrategyDecoder for AsChoice {
+    fn response(&self, rules: &Rules, round: &Round) -> Option<Choice> {
+        rules.choice(round.column as usize)
+    }
+}
+
+/// Reads X, Y and Z as the outcome to get: a loss, a draw or a win (part 2).
+#[derive(Clone, Copy, Debug)]
+pub struct AsOutcome;
+
+impl StrategyDecoder for AsOutcome {
+    fn response(&self, rules: &Rules, round: &Round) -> Option<Choice> {
+        let expected = [Outcome::Loss, Outcome::Draw, Outcome::Win][round.column as usize];
+        Some(expected.to_response(rules, round.opponent))
+    }
+}
+
+/// Reads X, Y and Z as the given choices, which may be of other rules than those played.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mapping(pub [Choice; Round::COLUMNS]);
+
+impl StrategyDecoder for Mapping {
+    fn response(&self, rules: &Rules, round: &Round) -> Option<Choice> {
+        rules.choice(self.0[round.column as usize].index())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Goal {
+    Maximize,
+    Minimize,
+}
+
+/// The mapping of X, Y and Z to distinct choices which scores the most or the least over
+/// `rounds`, along with its total. The first mapping in the order of the choices wins ties.
+///
+/// Returns `None` if the rules have fewer choices than there are letters, which
+/// [`Rules::parse`] rules out.
+pub fn search_mapping<'a>(
+    rules: &Rules,
+    rounds: impl IntoIterator<Item = &'a Round>,
+    goal: Goal,
+) -> Option<(Mapping, u32)> {
+    // Only the number of rounds of each kind matters
+    let mut counts = vec![[0; Round::COLUMNS]; rules.size()];
+    for round in rounds {
+        counts[round.opponent.index()][round.column as usize] += 1;
+    }
+
+    let total = |mapping: &Mapping| -> u32 {
+        rules
+            .choices()
+            .zip(&counts)
+            .flat_map(|(opponent, row)| {
+                row.iter()
+                    .zip(mapping.0)
+                    .map(move |(&n, us)| n * rules.score(us, opponent))
+            })
+            .sum()
+    };
+
+    let mut best: Option<(Mapping, u32)> = None;
+    for x in rules.choices() {
+        for y in rules.choices().filter(|&y| y != x) {
+            for z in rules.choices().filter(|&z| z != x && z != y) {
+                let mapping = Mapping([x, y, z]);
+                let score = total(&mapping);
+                let better = match (best, goal) {
+                    (None, _) => true,
+                    (Some((_, b)), Goal::Maximize) => score > b,
+                    (Some((_, b)), Goal::Minimize) => score < b,
+                };
+                if better {
+                    best = Some((mapping, score));
+                }
+            }
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day02, Solver};
+
+    #[test]
+    fn test_search_mapping() {
+        let rules = Rules::classic();
+        let rounds = Day02::parse(Day02::EXAMPLE.input).unwrap();
+        let [rock, paper, scissors] = [Choice::ROCK, Choice::PAPER, Choice::SCISSORS];
+
+        // A Y, B X, C Z: always win, playing paper, scissors then rock
+        let (mapping, best) = search_mapping(rules, &rounds, Goal::Maximize).unwrap();
+        assert_eq!((mapping, best), (Mapping([scissors, paper, rock]), 24));
+        assert_eq!(mapping.total(rules, &rounds), Some(best));
+
+        let (mapping, worst) = search_mapping(rules, &rounds, Goal::Minimize).unwrap();
+        assert_eq!((mapping, worst), (Mapping([rock, scissors, paper]), 6));
+
+        assert_eq!(AsChoice.total(rules, &rounds), Some(15));
+        assert_eq!(AsOutcome.total(rules, &rounds), Some(12));
+    }
+
+    #[test]
+    fn test_other_rules() {
+        let rpsls = Rules::parse(include_str!("../rules/rpsls.txt")).unwrap();
+        let classic = Rules::classic();
+        let rounds = Day02::parse(Day02::EXAMPLE.input).unwrap();
+        let spock = rpsls.find("Spock").unwrap();
+
+        // Spock isn't a classic choice, unlike those of the letters
+        let mapping = Mapping([Choice::ROCK, spock, Choice::PAPER]);
+        assert_eq!(mapping.points(classic, &rounds[1]), Some(1));
+        assert_eq!(mapping.response(classic, &rounds[0]), None);
+        assert_eq!(mapping.total(classic, &rounds), None);
+        assert!(mapping.total(&rpsls, &rounds).is_some());
+        assert_eq!(AsChoice.total(&rpsls, &rounds), Some(15));
+    }
+}
@@ -0,0 +1,288 @@
+use aoc_common::{Answer, Error, Example, ParseError, Result, Solver, Streaming};
+
+mod decoder;
+mod rules;
+pub mod sim;
+
+pub use decoder::{search_mapping, AsChoice, AsOutcome, Goal, Mapping, StrategyDecoder};
+pub use rules::{Choice, Outcome, Rules};
+
+pub struct Day02;
+
+/// A line of the strategy guide, whose second column isn't decoded yet.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Round {
+    pub opponent: Choice,
+    /// 0, 1, 2 for X, Y, Z
+    pub column: u8,
+}
+
+impl Round {
+    /// Number of letters of the second column.
+    pub const COLUMNS: usize = 3;
+
+    /// Parses a strategy guide line.
+    /// Format: \[ABC\]<space>\[XYZ\]
+    pub fn parse(data: &str) -> std::result::Result<Round, ParseError> {
+        let data = data.trim_end();
+
+        let opponent = match data.chars().next() {
+            Some('A') => Choice::ROCK,
+            Some('B') => Choice::PAPER,
+            Some('C') => Choice::SCISSORS,
+            Some(c) => {
+                let c = &data[..c.len_utf8()];
+                return Err(ParseError::new(data, c, "expected 'A', 'B' or 'C'"));
+            }
+            None => return Err(ParseError::eof(data, "expected 'A', 'B' or 'C'")),
+        };
+
+        // The opponent's letter is ASCII
+        let Some(rest) = data[1..].strip_prefix(' ') else {
+            return Err(ParseError::new(data, &data[1..], "expected a space"));
+        };
+
+        let column = match rest {
+            "X" => 0,
+            "Y" => 1,
+            "Z" => 2,
+            _ => return Err(ParseError::new(data, rest, "expected 'X', 'Y' or 'Z'")),
+        };
+
+        Ok(Round { opponent, column })
+    }
+
+    /// Computes points, reading the second column as our response (part 1).
+    ///
+    /// X : Rock
+    /// Y : Paper
+    /// Z : Scissors
+    pub fn points_part1(&self) -> u32 {
+        AsChoice
+            .points(Rules::classic(), self)
+            .expect("Classic rules have a choice for each letter")
+    }
+
+    /// Computes points, reading the second column as the expected outcome (part 2).
+    ///
+    /// X : Loss
+    /// Y : Draw
+    /// Z : Win
+    pub fn points_part2(&self) -> u32 {
+        AsOutcome
+            .points(Rules::classic(), self)
+            .expect("Outcomes have a response")
+    }
+}
+
+/// Computes points from a strategy guide line (part 1).
+/// # Arguments
+/// * `data` - Strategy guide line.
+///
+/// Format: \[ABC\]<space>\[XYZ\]
+///
+/// A, X : Rock
+/// B, Y : Paper
+/// C, Z : Scissors
+///
+/// A, B, C : Opponent
+/// X, Y, Z : Our response
+/// # Examples
+/// ```
+/// assert_eq!(d02::points_part1("A Y"), Some(8));
+/// ```
+pub fn points_part1(data: &str) -> Option<u32> {
+    Round::parse(data).ok().map(|round| round.points_part1())
+}
+
+/// Computes points from a strategy guide line (part 2).
+/// # Arguments
+/// * `data` - Strategy guide line.
+///
+/// Format: \[ABC\]<space>\[XYZ\]
+///
+/// A : Rock
+/// B : Paper
+/// C : Scissors
+///
+/// X : Loss
+/// Y : Draw
+/// Z : Win
+///
+/// A, B, C : Opponent
+/// X, Y, Z : Our response
+/// # Examples
+/// ```
+/// assert_eq!(d02::points_part2("A Y"), Some(4));
+/// ```
+pub fn points_part2(data: &str) -> Option<u32> {
+    Round::parse(data).ok().map(|round| round.points_part2())
+}
+
+/// Running scores of a streamed strategy guide.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Scores {
+    pub part1: u32,
+    pub part2: u32,
+}
+
+impl Streaming for Day02 {
+    type State = Scores;
+
+    fn feed(scores: &mut Scores, line: &str) -> std::result::Result<(), ParseError> {
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+
+        let round = Round::parse(line)?;
+        scores.part1 += round.points_part1();
+        scores.part2 += round.points_part2();
+        Ok(())
+    }
+
+    fn part1(scores: &Scores) -> Result<Answer> {
+        Ok(scores.part1.into())
+    }
+
+    fn part2(scores: &Scores) -> Result<Answer> {
+        Ok(scores.part2.into())
+    }
+}
+
+impl Solver for Day02 {
+    const DAY: u8 = 2;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+A Y
+B X
+C Z
+",
+        part1: "15",
+        part2: "12",
+    };
+
+    type Input = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Vec<Round>> {
+        Ok(aoc_common::parse_lines(input, Round::parse)?)
+    }
+
+    fn part1(rounds: &Vec<Round>) -> Result<Answer> {
+        let total = AsChoice.total(Rules::classic(), rounds);
+        Ok(total.ok_or(Error::NoSolution)?.into())
+    }
+
+    fn part2(rounds: &Vec<Round>) -> Result<Answer> {
+        let total = AsOutcome.total(Rules::classic(), rounds);
+        Ok(total.ok_or(Error::NoSolution)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Outcome::*;
+
+    const ROCK: Choice = Choice::ROCK;
+    const PAPER: Choice = Choice::PAPER;
+    const SCISSORS: Choice = Choice::SCISSORS;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day02>();
+        aoc_common::check_stream_example::<Day02>();
+    }
+
+    #[test]
+    fn outcome_from_game() {
+        let rules = Rules::classic();
+        let game = |us, opponent| Outcome::from_game(rules, us, opponent);
+
+        assert_eq!(game(ROCK, SCISSORS), Win);
+        assert_eq!(game(ROCK, ROCK), Draw);
+        assert_eq!(game(ROCK, PAPER), Loss);
+
+        assert_eq!(game(PAPER, ROCK), Win);
+        assert_eq!(game(PAPER, PAPER), Draw);
+        assert_eq!(game(PAPER, SCISSORS), Loss);
+
+        assert_eq!(game(SCISSORS, PAPER), Win);
+        assert_eq!(game(SCISSORS, SCISSORS), Draw);
+        assert_eq!(game(SCISSORS, ROCK), Loss);
+    }
+
+    #[test]
+    fn outcome_to_response() {
+        let rules = Rules::classic();
+
+        assert_eq!(Win.to_response(rules, ROCK), PAPER);
+        assert_eq!(Win.to_response(rules, PAPER), SCISSORS);
+        assert_eq!(Win.to_response(rules, SCISSORS), ROCK);
+
+        assert_eq!(Draw.to_response(rules, ROCK), ROCK);
+        assert_eq!(Draw.to_response(rules, PAPER), PAPER);
+        assert_eq!(Draw.to_response(rules, SCISSORS), SCISSORS);
+
+        assert_eq!(Loss.to_response(rules, ROCK), SCISSORS);
+        assert_eq!(Loss.to_response(rules, PAPER), ROCK);
+        assert_eq!(Loss.to_response(rules, SCISSORS), PAPER);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Round::parse("D X").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (1, "D"));
+
+        let e = Round::parse("AX").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (2, "X"));
+
+        let e = Round::parse("A W").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (3, "W"));
+
+        let e = Round::parse("A").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (2, ""));
+    }
+
+    #[test]
+    fn wins_part1() {
+        assert_eq!(points_part1("A Y"), Some(8));
+        assert_eq!(points_part1("B Z"), Some(9));
+        assert_eq!(points_part1("C X"), Some(7));
+    }
+
+    #[test]
+    fn ties_part1() {
+        assert_eq!(points_part1("A X"), Some(4));
+        assert_eq!(points_part1("B Y"), Some(5));
+        assert_eq!(points_part1("C Z"), Some(6));
+    }
+
+    #[test]
+    fn losses_part1() {
+        assert_eq!(points_part1("A Z"), Some(3));
+        assert_eq!(points_part1("B X"), Some(1));
+        assert_eq!(points_part1("C Y"), Some(2));
+    }
+
+    #[test]
+    fn wins_part2() {
+        assert_eq!(points_part2("A Z"), Some(8));
+        assert_eq!(points_part2("B Z"), Some(9));
+        assert_eq!(points_part2("C Z"), Some(7));
+    }
+
+    #[test]
+    fn ties_part2() {
+        assert_eq!(points_part2("A Y"), Some(4));
+        assert_eq!(points_part2("B Y"), Some(5));
+        assert_eq!(points_part2("C Y"), Some(6));
+    }
+
+    #[test]
+    fn losses_part2() {
+        assert_eq!(points_part2("A X"), Some(3));
+        assert_eq!(points_part2("B X"), Some(1));
+        assert_eq!(points_part2("C X"), Some(2));
+    }
+}
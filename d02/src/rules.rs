@@ -0,0 +1,332 @@
+use std::sync::OnceLock;
+
+use aoc_common::ParseError;
+
+/// One of the choices of a game, numbered in the order of its [`Rules`], which are the
+/// only ones to build them.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash, PartialOrd, Ord)]
+pub struct Choice(u8);
+
+impl Choice {
+    /// Choices of the [classic](Rules::classic) game.
+    pub const ROCK: Choice = Choice(0);
+    pub const PAPER: Choice = Choice(1);
+    pub const SCISSORS: Choice = Choice(2);
+
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Win, Outcome::Draw, Outcome::Loss];
+
+    /// Determine outcome from a game.
+    pub fn from_game(rules: &Rules, us: Choice, opponent: Choice) -> Outcome {
+        if rules.beats(us, opponent) {
+            Outcome::Win
+        } else if us == opponent {
+            Outcome::Draw
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /// Determine response which will give this outcome, the first one of the rules if
+    /// several would.
+    pub fn to_response(self, rules: &Rules, opponent: Choice) -> Choice {
+        rules
+            .choices()
+            .find(|&us| Outcome::from_game(rules, us, opponent) == self)
+            .expect("Balanced rules of 3 choices or more have a response for every outcome")
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Outcome::Win => "win",
+            Outcome::Draw => "draw",
+            Outcome::Loss => "loss",
+        }
+    }
+}
+
+/// A balanced game of rock-paper-scissors, with any odd number of choices from
+/// [`MIN_CHOICES`](Self::MIN_CHOICES): each choice beats half of the others, and is
+/// beaten by the other half.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    names: Vec<String>,
+    points: Vec<u32>,
+    /// Indexed by [`Outcome`].
+    outcome_points: [u32; 3],
+    /// `beats[a][b]` if `a` beats `b`.
+    beats: Vec<Vec<bool>>,
+}
+
+const CLASSIC: &str = include_str!("../rules/classic.txt");
+
+impl Rules {
+    /// Fewest choices for every outcome to be possible.
+    pub const MIN_CHOICES: usize = 3;
+
+    /// Rock, paper and scissors, scored as the puzzle does.
+    pub fn classic() -> &'static Rules {
+        static CLASSIC_RULES: OnceLock<Rules> = OnceLock::new();
+        CLASSIC_RULES.get_or_init(|| Rules::parse(CLASSIC).expect("Invalid built-in rules"))
+    }
+
+    /// Parses a game definition, made of lines of:
+    /// - `choice <name> <points>`, choices being numbered in order,
+    /// - `outcome <win|draw|loss> <points>`,
+    /// - `beats <name> <name>...`, the first choice beating the others.
+    ///
+    /// Blank lines and lines starting with `#` are ignored.
+    pub fn parse(content: &str) -> Result<Rules, ParseError> {
+        let mut names: Vec<&str> = Vec::new();
+        let mut points = Vec::new();
+        let mut outcome_points = [None; 3];
+        let mut edges = Vec::new();
+
+        for line in aoc_common::lines(content).filter(|l| !l.trim_start().starts_with('#')) {
+            let mut words = line.split_whitespace();
+            let keyword = words.next().expect("Lines aren't blank");
+            let mut word = |expected| {
+                words
+                    .next()
+                    .ok_or_else(|| ParseError::new(content, &line[line.len()..], expected))
+            };
+            let number = |word: &str| {
+                word.parse::<u32>()
+                    .map_err(|_| ParseError::new(content, word, "expected points"))
+            };
+
+            match keyword {
+                "choice" => {
+                    let name = word("expected a name")?;
+                    if names.contains(&name) {
+                        return Err(ParseError::new(content, name, "duplicate choice"));
+                    }
+                    points.push(number(word("expected points")?)?);
+                    names.push(name);
+                }
+                "outcome" => {
+                    let name = word("expected 'win', 'draw' or 'loss'")?;
+                    let outcome = Outcome::ALL
+                        .into_iter()
+                        .find(|o| o.name() == name)
+                        .ok_or_else(|| {
+                            ParseError::new(content, name, "expected 'win', 'draw' or 'loss'")
+                        })?;
+                    outcome_points[outcome as usize] = Some(number(word("expected points")?)?);
+                }
+                "beats" => {
+                    let winner = word("expected a choice")?;
+                    for loser in words.by_ref() {
+                        edges.push((winner, loser));
+                    }
+                }
+                _ => {
+                    return Err(ParseError::new(
+                        content,
+                        keyword,
+                        "expected 'choice', 'outcome' or 'beats'",
+                    ))
+                }
+            }
+            if let Some(extra) = words.next() {
+                return Err(ParseError::new(content, extra, "unexpected text"));
+            }
+        }
+
+        if names.len() < Self::MIN_CHOICES {
+            let msg = format!("expected at least {} choices", Self::MIN_CHOICES);
+            return Err(match names.last() {
+                Some(last) => ParseError::new(content, last, msg),
+                None => ParseError::eof(content, msg),
+            });
+        }
+        if let Some(extra) = names.get(usize::from(u8::MAX)) {
+            let msg = format!("too many choices (at most {})", u8::MAX);
+            return Err(ParseError::new(content, extra, msg));
+        }
+        if names.len().is_multiple_of(2) {
+            return Err(ParseError::eof(
+                content,
+                "expected an odd number of choices",
+            ));
+        }
+
+        let find = |name: &str| {
+            names
+                .iter()
+                .position(|&n| n == name)
+                .ok_or_else(|| ParseError::new(content, name, "unknown choice"))
+        };
+        let mut beats = vec![vec![false; names.len()]; names.len()];
+        for (winner, loser) in edges {
+            let (w, l) = (find(winner)?, find(loser)?);
+            if w == l || beats[l][w] {
+                return Err(ParseError::new(content, loser, "contradicts another rule"));
+            }
+            beats[w][l] = true;
+        }
+
+        let half = names.len() / 2;
+        for (name, row) in names.iter().zip(&beats) {
+            if row.iter().filter(|&&b| b).count() != half {
+                return Err(ParseError::new(
+                    content,
+                    name,
+                    format!("must beat exactly {half} other choice(s)"),
+                ));
+            }
+        }
+
+        let mut outcomes = [0; 3];
+        for (outcome, points) in Outcome::ALL.into_iter().zip(&mut outcomes) {
+            *points = outcome_points[outcome as usize].ok_or_else(|| {
+                ParseError::eof(content, format!("missing points for a {}", outcome.name()))
+            })?;
+        }
+
+        Ok(Rules {
+            names: names.into_iter().map(String::from).collect(),
+            points,
+            outcome_points: outcomes,
+            beats,
+        })
+    }
+
+    /// Number of choices.
+    pub fn size(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Every choice, in order.
+    pub fn choices(&self) -> impl Iterator<Item = Choice> {
+        (0..self.names.len() as u8).map(Choice)
+    }
+
+    /// Panics if `choice` isn't one of these rules.
+    pub fn name(&self, choice: Choice) -> &str {
+        &self.names[choice.index()]
+    }
+
+    /// The choice numbered `index`, from 0.
+    pub fn choice(&self, index: usize) -> Option<Choice> {
+        (index < self.names.len()).then_some(Choice(index as u8))
+    }
+
+    pub fn find(&self, name: &str) -> Option<Choice> {
+        self.names
+            .iter()
+            .position(|n| n == name)
+            .map(|i| Choice(i as u8))
+    }
+
+    pub fn beats(&self, us: Choice, opponent: Choice) -> bool {
+        self.beats[us.index()][opponent.index()]
+    }
+
+    /// Points for playing `choice`, whatever the outcome.
+    pub fn choice_points(&self, choice: Choice) -> u32 {
+        self.points[choice.index()]
+    }
+
+    pub fn outcome_points(&self, outcome: Outcome) -> u32 {
+        self.outcome_points[outcome as usize]
+    }
+
+    /// Points scored by playing `us` against `opponent`.
+    pub fn score(&self, us: Choice, opponent: Choice) -> u32 {
+        self.choice_points(us) + self.outcome_points(Outcome::from_game(self, us, opponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RPSLS: &str = include_str!("../rules/rpsls.txt");
+
+    #[test]
+    fn test_rpsls() {
+        let rules = Rules::parse(RPSLS).unwrap();
+        let [rock, paper, scissors, spock, lizard] =
+            ["Rock", "Paper", "Scissors", "Spock", "Lizard"].map(|n| rules.find(n).unwrap());
+
+        assert_eq!(Outcome::from_game(&rules, spock, rock), Outcome::Win);
+        assert_eq!(Outcome::from_game(&rules, lizard, lizard), Outcome::Draw);
+        assert_eq!(Outcome::from_game(&rules, paper, lizard), Outcome::Loss);
+
+        // Paper and Spock both beat rock, paper coming first
+        assert_eq!(Outcome::Win.to_response(&rules, rock), paper);
+        assert_eq!(Outcome::Loss.to_response(&rules, spock), rock);
+        assert_eq!(Outcome::Loss.to_response(&rules, scissors), paper);
+        assert_eq!(rules.score(lizard, spock), 11);
+        assert_eq!(rules.choice(4), Some(lizard));
+        assert_eq!(rules.choice(5), None);
+
+        for opponent in rules.choices() {
+            for outcome in Outcome::ALL {
+                let us = outcome.to_response(&rules, opponent);
+                assert_eq!(Outcome::from_game(&rules, us, opponent), outcome);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |content: &str| {
+            let e = Rules::parse(content).unwrap_err();
+            (e.line, e.column, e.message)
+        };
+        let rps = "choice R 1\nchoice P 2\nchoice S 3\noutcome win 6\noutcome draw 3\n\
+            outcome loss 0\nbeats R S\nbeats P R\n";
+
+        assert_eq!(
+            error(&format!("{rps}beats S P\nbeats P S")),
+            (10, 9, "contradicts another rule".to_string())
+        );
+        assert_eq!(
+            error(&format!("{rps}beats S Q")),
+            (9, 9, "unknown choice".to_string())
+        );
+        assert_eq!(
+            error(rps),
+            (3, 8, "must beat exactly 1 other choice(s)".to_string())
+        );
+        assert_eq!(
+            error("choice R one"),
+            (1, 10, "expected points".to_string())
+        );
+        assert_eq!(
+            error("choice R 1\nchoice P 1\nchoice S 1\nchoice L 1"),
+            (4, 11, "expected an odd number of choices".to_string())
+        );
+        let many: String = (0..257).map(|i| format!("choice C{i} 1\n")).collect();
+        assert_eq!(
+            error(&many),
+            (256, 8, "too many choices (at most 255)".to_string())
+        );
+        assert_eq!(
+            error("choice Only 1\noutcome win 6\noutcome draw 3\noutcome loss 0"),
+            (1, 8, "expected at least 3 choices".to_string())
+        );
+        assert_eq!(
+            error("outcome win 6"),
+            (1, 14, "expected at least 3 choices".to_string())
+        );
+        assert_eq!(
+            error("choice R 1\noutcome tie 3"),
+            (2, 9, "expected 'win', 'draw' or 'loss'".to_string())
+        );
+    }
+}
@@ -0,0 +1,333 @@
+//! Repeated games between strategies.
+
+use std::collections::BTreeMap;
+
+use crate::{Choice, Outcome, Round, Rules, StrategyDecoder};
+
+/// A round already played, from the point of view of one player.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Played {
+    pub ours: Choice,
+    pub theirs: Choice,
+}
+
+/// A player choosing its move from the rounds played so far.
+pub trait Agent {
+    /// `history` is oldest first, from this agent's point of view.
+    fn play(&mut self, rules: &Rules, history: &[Played]) -> Choice;
+}
+
+/// Always plays the same choice.
+#[derive(Clone, Copy, Debug)]
+pub struct Fixed(pub Choice);
+
+impl Agent for Fixed {
+    fn play(&mut self, _rules: &Rules, _history: &[Played]) -> Choice {
+        self.0
+    }
+}
+
+/// Plays the given choices in turn, over and over.
+#[derive(Clone, Debug)]
+pub struct Cyclic(Vec<Choice>);
+
+impl Cyclic {
+    /// `None` if there are no choices to play.
+    pub fn new(choices: Vec<Choice>) -> Option<Self> {
+        (!choices.is_empty()).then_some(Cyclic(choices))
+    }
+}
+
+impl Agent for Cyclic {
+    fn play(&mut self, _rules: &Rules, history: &[Played]) -> Choice {
+        self.0[history.len() % self.0.len()]
+    }
+}
+
+/// Replays the moves of a strategy guide, over and over.
+#[derive(Clone, Debug)]
+pub struct FromGuide(Vec<Choice>);
+
+impl FromGuide {
+    /// Plays our side of the guide, read by `decoder`, or `None` if there's no response
+    /// to one of the rounds or no round at all.
+    pub fn ours(rules: &Rules, rounds: &[Round], decoder: &impl StrategyDecoder) -> Option<Self> {
+        let choices = rounds
+            .iter()
+            .map(|round| decoder.response(rules, round))
+            .collect::<Option<_>>()?;
+        FromGuide::new(choices)
+    }
+
+    /// Plays the opponent's side of the guide, or `None` if there's no round.
+    pub fn theirs(rounds: &[Round]) -> Option<Self> {
+        FromGuide::new(rounds.iter().map(|round| round.opponent).collect())
+    }
+
+    fn new(choices: Vec<Choice>) -> Option<Self> {
+        (!choices.is_empty()).then_some(FromGuide(choices))
+    }
+}
+
+impl Agent for FromGuide {
+    fn play(&mut self, _rules: &Rules, history: &[Played]) -> Choice {
+        self.0[history.len() % self.0.len()]
+    }
+}
+
+/// Running counts of the opponent's choices, so that agents don't go through the whole
+/// history every round.
+#[derive(Clone, Debug, Default)]
+struct Habits {
+    /// Whether `after` is kept.
+    followers: bool,
+    /// Rounds of the history counted so far.
+    seen: usize,
+    /// Times each choice was played.
+    overall: Vec<u64>,
+    /// Times each choice was played right after each other one, at `size * previous + next`.
+    after: Vec<u64>,
+}
+
+impl Habits {
+    /// Counts the rounds played since the last update, starting over on a new game.
+    fn update(&mut self, rules: &Rules, history: &[Played]) {
+        let size = rules.size();
+        if history.len() < self.seen || self.overall.len() != size {
+            self.seen = 0;
+            self.overall = vec![0; size];
+            self.after = vec![0; if self.followers { size * size } else { 0 }];
+        }
+
+        for i in self.seen..history.len() {
+            let theirs = history[i].theirs.index();
+            self.overall[theirs] += 1;
+            if let (true, Some(previous)) = (self.followers, i.checked_sub(1)) {
+                self.after[size * history[previous].theirs.index() + theirs] += 1;
+            }
+        }
+        self.seen = history.len();
+    }
+
+    /// Times each choice was played right after `previous`.
+    fn after(&self, previous: Choice) -> &[u64] {
+        let size = self.overall.len();
+        &self.after[size * previous.index()..][..size]
+    }
+}
+
+/// Beats the opponent's most frequent choice so far, the first one on ties. Rules always
+/// have a winning response, as they have at least [`Rules::MIN_CHOICES`].
+#[derive(Clone, Debug, Default)]
+pub struct FrequencyCounter {
+    habits: Habits,
+}
+
+impl Agent for FrequencyCounter {
+    fn play(&mut self, rules: &Rules, history: &[Played]) -> Choice {
+        self.habits.update(rules, history);
+        let counts = &self.habits.overall;
+        let favorite = rules
+            .choices()
+            .max_by_key(|c| (counts[c.index()], std::cmp::Reverse(*c)))
+            .expect("Rules have choices");
+        Outcome::Win.to_response(rules, favorite)
+    }
+}
+
+/// Plays the choice with the best expected score against the opponent's observed
+/// habits: what they played after their previous move, or overall if that was never seen.
+#[derive(Clone, Debug)]
+pub struct BestResponse {
+    habits: Habits,
+}
+
+impl Default for BestResponse {
+    fn default() -> Self {
+        BestResponse {
+            habits: Habits {
+                followers: true,
+                ..Habits::default()
+            },
+        }
+    }
+}
+
+impl BestResponse {
+    /// The choice scoring the most on average against choices played `counts` times.
+    pub fn against(rules: &Rules, counts: &[u64]) -> Choice {
+        rules
+            .choices()
+            .max_by_key(|&us| {
+                let expected: u64 = rules
+                    .choices()
+                    .map(|theirs| counts[theirs.index()] * u64::from(rules.score(us, theirs)))
+                    .sum();
+                (expected, std::cmp::Reverse(us))
+            })
+            .expect("Rules have choices")
+    }
+}
+
+impl Agent for BestResponse {
+    fn play(&mut self, rules: &Rules, history: &[Played]) -> Choice {
+        self.habits.update(rules, history);
+        let counts = history
+            .last()
+            .map(|last| self.habits.after(last.theirs))
+            .filter(|followers| followers.iter().any(|&n| n > 0))
+            .unwrap_or(&self.habits.overall);
+        BestResponse::against(rules, counts)
+    }
+}
+
+/// Results of one side of a simulation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Side {
+    pub total: u64,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    /// How many rounds scored each number of points.
+    pub points: BTreeMap<u32, usize>,
+}
+
+impl Side {
+    fn record(&mut self, points: u32, outcome: Outcome) {
+        self.total += u64::from(points);
+        *self.points.entry(points).or_default() += 1;
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::Loss => self.losses += 1,
+        }
+    }
+
+    /// Average points per round.
+    pub fn mean(&self) -> f64 {
+        let rounds = self.wins + self.draws + self.losses;
+        self.total as f64 / rounds.max(1) as f64
+    }
+}
+
+/// Plays `rounds` rounds between `a` and `b`, returning the results of each.
+pub fn simulate(
+    rules: &Rules,
+    a: &mut dyn Agent,
+    b: &mut dyn Agent,
+    rounds: usize,
+) -> (Side, Side) {
+    let mut history_a = Vec::with_capacity(rounds);
+    let mut history_b = Vec::with_capacity(rounds);
+    let (mut side_a, mut side_b) = (Side::default(), Side::default());
+
+    for _ in 0..rounds {
+        let choice_a = a.play(rules, &history_a);
+        let choice_b = b.play(rules, &history_b);
+
+        side_a.record(
+            rules.score(choice_a, choice_b),
+            Outcome::from_game(rules, choice_a, choice_b),
+        );
+        side_b.record(
+            rules.score(choice_b, choice_a),
+            Outcome::from_game(rules, choice_b, choice_a),
+        );
+
+        history_a.push(Played {
+            ours: choice_a,
+            theirs: choice_b,
+        });
+        history_b.push(Played {
+            ours: choice_b,
+            theirs: choice_a,
+        });
+    }
+
+    (side_a, side_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AsChoice, Day02, Solver};
+
+    const ROCK: Choice = Choice::ROCK;
+    const PAPER: Choice = Choice::PAPER;
+    const SCISSORS: Choice = Choice::SCISSORS;
+
+    #[test]
+    fn test_frequency_counter() {
+        let rules = Rules::classic();
+        let (fixed, counter) = simulate(
+            rules,
+            &mut Fixed(ROCK),
+            &mut FrequencyCounter::default(),
+            100,
+        );
+
+        // Paper from the start, as nothing was seen yet and rock comes first
+        assert_eq!((counter.wins, counter.total), (100, 800));
+        assert_eq!(fixed.points, BTreeMap::from([(1, 100)]));
+    }
+
+    #[test]
+    fn test_frequency_counter_rpsls() {
+        let rules = Rules::parse(include_str!("../rules/rpsls.txt")).unwrap();
+        let spock = rules.find("Spock").unwrap();
+        let (_, counter) = simulate(
+            &rules,
+            &mut Fixed(spock),
+            &mut FrequencyCounter::default(),
+            10,
+        );
+
+        // Paper beats Spock, as well as rock, which is expected in the first round
+        assert_eq!((counter.wins, counter.total), (10, 80));
+    }
+
+    #[test]
+    fn test_best_response() {
+        let rules = Rules::classic();
+        let mut cycle = Cyclic::new(vec![ROCK, PAPER, SCISSORS]).unwrap();
+        let (cyclic, best) = simulate(rules, &mut cycle, &mut BestResponse::default(), 100);
+
+        // The first 4 rounds are spent learning what follows each choice
+        assert_eq!((best.wins, best.draws, best.losses), (96, 2, 2));
+        assert_eq!(cyclic.wins, best.losses);
+        assert!(best.mean() > 7.);
+
+        assert_eq!(BestResponse::against(rules, &[0, 0, 5]), ROCK);
+        assert_eq!(BestResponse::against(rules, &[1, 1, 1]), SCISSORS);
+    }
+
+    #[test]
+    fn test_from_guide() {
+        let rules = Rules::classic();
+        let rounds = Day02::parse(Day02::EXAMPLE.input).unwrap();
+        let mut ours = FromGuide::ours(rules, &rounds, &AsChoice).unwrap();
+        let mut theirs = FromGuide::theirs(&rounds).unwrap();
+
+        let (us, _) = simulate(rules, &mut ours, &mut theirs, 2 * rounds.len());
+        assert_eq!(us.total, 2 * 15);
+
+        assert!(FromGuide::theirs(&[]).is_none());
+        assert!(FromGuide::ours(rules, &[], &AsChoice).is_none());
+        assert!(Cyclic::new(vec![]).is_none());
+    }
+
+    #[test]
+    fn test_long_game() {
+        let rules = Rules::classic();
+        let mut cycle = Cyclic::new(vec![ROCK, ROCK, PAPER, SCISSORS]).unwrap();
+        let mut best = BestResponse::default();
+        let (_, best) = simulate(rules, &mut cycle, &mut best, 1_000_000);
+        assert!(best.wins > 700_000);
+
+        // Agents start over on a new game
+        let mut counter = FrequencyCounter::default();
+        simulate(rules, &mut Fixed(ROCK), &mut counter, 10);
+        let (_, counter) = simulate(rules, &mut Fixed(SCISSORS), &mut counter, 10);
+        assert_eq!(counter.wins, 9);
+    }
+}
@@ -0,0 +1,124 @@
+//! Compares the `RucksackSet` bitmask with the `HashSet` intersections it replaced.
+//!
+//! Run with `cargo bench -p d03`.
+
+use std::{
+    collections::HashSet,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_common::random::XorShift;
+use d03::{get_priority_group, get_priority_line, PriorityTable};
+
+/// The priority of an item of the puzzle.
+fn priority_value(c: char) -> u32 {
+    PriorityTable::standard().priority(c).expect("Not an item")
+}
+
+fn bitmask_line(line: &str) -> u32 {
+    get_priority_line(PriorityTable::standard(), line).expect("a line didn't match")
+}
+
+fn bitmask_group(group: &[&str]) -> u32 {
+    get_priority_group(PriorityTable::standard(), group).expect("Unable to find intersection")
+}
+
+/// The previous implementation of `get_priority_line`.
+fn hashset_line(line: &str) -> u32 {
+    let chars: Vec<_> = line.chars().collect();
+    let mid = chars.len() / 2;
+
+    let first_set: HashSet<_> = chars[..mid].iter().collect();
+
+    let value = chars[mid..]
+        .iter()
+        .find(|c| first_set.contains(c))
+        .expect("a line didn't match");
+
+    priority_value(*value)
+}
+
+/// The previous implementation of `get_priority_group`.
+fn hashset_group(group: &[&str]) -> u32 {
+    let mut chars: Vec<HashSet<_>> = group.iter().map(|line| line.chars().collect()).collect();
+
+    let (first, others) = chars.split_at_mut(1);
+
+    first[0].retain(|elem| others[0].contains(elem) && others[1].contains(elem));
+    priority_value(*first[0].iter().next().expect("Unable to find intersection"))
+}
+
+/// Groups of 3 rucksacks of 2 × 16 items, with a single item shared by both halves of
+/// each rucksack, and a single one by the whole group.
+fn rucksacks(groups: usize) -> Vec<String> {
+    let mut rng = XorShift::default();
+    let mut next = |n: usize| rng.below(n as u64) as usize;
+
+    let mut letters: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut lines = Vec::with_capacity(3 * groups);
+    for _ in 0..groups {
+        for i in (1..letters.len()).rev() {
+            letters.swap(i, next(i + 1));
+        }
+
+        // A badge, an item common to both halves of each rucksack, then 6 disjoint
+        // pools of 8 items for the halves
+        let (badge, rest) = letters.split_first().unwrap();
+        let (commons, pools) = rest.split_at(3);
+        let mut pools = pools.chunks_exact(8);
+        for &common in commons {
+            let mut half = |len| -> String {
+                let pool = pools.next().unwrap();
+                (0..len).map(|_| pool[next(pool.len())]).collect()
+            };
+            let (first, second) = (half(14), half(15));
+            lines.push(format!("{badge}{common}{first}{common}{second}"));
+        }
+    }
+    lines
+}
+
+/// Median time of running `f` over every item of `inputs`.
+fn time<T>(inputs: &[T], f: impl Fn(&T) -> u32) -> Duration {
+    let mut samples: Vec<_> = (0..11)
+        .map(|_| {
+            let start = Instant::now();
+            let sum: u32 = inputs.iter().map(&f).sum();
+            black_box(sum);
+            start.elapsed()
+        })
+        .collect();
+    samples.sort_unstable();
+    samples[samples.len() / 2]
+}
+
+fn main() {
+    let lines = rucksacks(10_000);
+    let groups: Vec<Vec<&str>> = lines
+        .chunks_exact(3)
+        .map(|group| group.iter().map(String::as_str).collect())
+        .collect();
+
+    // Both implementations agree on inputs with unique common items
+    assert!(lines.iter().all(|l| hashset_line(l) == bitmask_line(l)));
+    assert!(groups.iter().all(|g| hashset_group(g) == bitmask_group(g)));
+
+    for (name, hashset, bitset) in [
+        (
+            "line",
+            time(&lines, |l| hashset_line(black_box(l))),
+            time(&lines, |l| bitmask_line(black_box(l))),
+        ),
+        (
+            "group",
+            time(&groups, |g| hashset_group(black_box(g))),
+            time(&groups, |g| bitmask_group(black_box(g))),
+        ),
+    ] {
+        println!(
+            "{name:<5}  HashSet {hashset:>10.1?}  RucksackSet {bitset:>10.1?}  ({:.1}x)",
+            hashset.as_secs_f64() / bitset.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use std::{
+    collections::HashMap,
+    ops::{BitAnd, BitOr},
+    sync::OnceLock,
+};
+
+use aoc_common::ParseError;
+
+/// Priorities of the items which may be in a rucksack, at most [`MAX_ITEMS`](Self::MAX_ITEMS).
+///
+/// Each item also gets a slot, which is its bit in a [`RucksackSet`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriorityTable {
+    /// Item and priority of each slot.
+    slots: Vec<(char, u32)>,
+    index: HashMap<char, u8>,
+}
+
+const STANDARD: &str = "a-z 1\nA-Z 27\n";
+
+impl PriorityTable {
+    pub const MAX_ITEMS: usize = 64;
+
+    /// The puzzle's items: a => 1, ... z => 26, A => 27, ... Z => 52.
+    pub fn standard() -> &'static PriorityTable {
+        static TABLE: OnceLock<PriorityTable> = OnceLock::new();
+        TABLE.get_or_init(|| PriorityTable::parse(STANDARD).expect("Invalid built-in table"))
+    }
+
+    /// Parses lines of `<items> <priority>`, the items getting consecutive priorities
+    /// from `priority`. Items are characters or ranges of characters such as `a-z`.
+    ///
+    /// Blank lines and lines starting with `#` are ignored.
+    /// # Examples
+    /// ```
+    /// let table = d03::PriorityTable::parse("a-c 1\nαβ 10").unwrap();
+    /// assert_eq!(table.priority('β'), Some(11));
+    /// ```
+    pub fn parse(definition: &str) -> Result<Self, ParseError> {
+        let mut table = PriorityTable {
+            slots: Vec::new(),
+            index: HashMap::new(),
+        };
+
+        for line in aoc_common::lines(definition).filter(|l| !l.trim_start().starts_with('#')) {
+            let line = line.trim();
+            let (items, priority) = line.rsplit_once(char::is_whitespace).ok_or_else(|| {
+                ParseError::new(definition, line, "expected items and a priority")
+            })?;
+            let first: u32 = priority
+                .parse()
+                .map_err(|_| ParseError::new(definition, priority, "expected a priority"))?;
+            // None past u32::MAX
+            let mut priority = Some(first);
+            let items = items.trim_end();
+
+            let chars: Vec<_> = items.char_indices().collect();
+            let mut i = 0;
+            while i < chars.len() {
+                let (start, first) = chars[i];
+                let last = match chars.get(i + 1..i + 3) {
+                    Some(&[(_, '-'), (_, last)]) => {
+                        i += 3;
+                        last
+                    }
+                    _ => {
+                        i += 1;
+                        first
+                    }
+                };
+
+                let end = chars.get(i).map_or(items.len(), |&(j, _)| j);
+                let span = &items[start..end];
+                if first > last {
+                    return Err(ParseError::new(definition, span, "empty range"));
+                }
+                for item in first..=last {
+                    if table.index.contains_key(&item) {
+                        return Err(ParseError::new(definition, span, "duplicate item"));
+                    }
+                    if table.slots.len() == Self::MAX_ITEMS {
+                        return Err(ParseError::new(definition, span, "too many items"));
+                    }
+                    let p = priority
+                        .ok_or_else(|| ParseError::new(definition, span, "priority too large"))?;
+                    table.index.insert(item, table.slots.len() as u8);
+                    table.slots.push((item, p));
+                    priority = p.checked_add(1);
+                }
+            }
+        }
+
+        Ok(table)
+    }
+
+    pub fn priority(&self, item: char) -> Option<u32> {
+        Some(self.slots[self.slot(item)? as usize].1)
+    }
+
+    /// Bit of `item` in a [`RucksackSet`].
+    pub fn slot(&self, item: char) -> Option<u8> {
+        self.index.get(&item).copied()
+    }
+
+    /// Items of a set, by increasing slot.
+    pub fn items(&self, set: RucksackSet) -> impl Iterator<Item = char> + '_ {
+        set.slots().map(|slot| self.slots[slot as usize].0)
+    }
+
+    /// Priorities of the items of a set, by increasing slot.
+    pub fn priorities(&self, set: RucksackSet) -> impl Iterator<Item = u32> + '_ {
+        set.slots().map(|slot| self.slots[slot as usize].1)
+    }
+
+    /// Whether `item` is in a set, which it never is without a priority.
+    pub fn contains(&self, set: RucksackSet, item: char) -> bool {
+        self.slot(item).is_some_and(|slot| set.contains(slot))
+    }
+
+    /// The set of `items`, or the first one without a priority.
+    pub fn set(&self, items: &str) -> Result<RucksackSet, char> {
+        let mut set = RucksackSet::EMPTY;
+        for item in items.chars() {
+            set.insert(self.slot(item).ok_or(item)?);
+        }
+        Ok(set)
+    }
+
+    /// Every item of the table.
+    pub fn all(&self) -> RucksackSet {
+        (0..self.slots.len() as u8).fold(RucksackSet::EMPTY, |mut set, slot| {
+            set.insert(slot);
+            set
+        })
+    }
+
+    /// Checks that a rucksack only contains items with a priority.
+    pub fn check(&self, line: &str) -> Result<(), ParseError> {
+        match line.char_indices().find(|&(_, c)| self.slot(c).is_none()) {
+            Some((i, c)) => Err(ParseError::new(
+                line,
+                &line[i..i + c.len_utf8()],
+                "item without a priority",
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A set of items, as one bit per slot of a [`PriorityTable`], which tells what they are.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RucksackSet(u64);
+
+impl RucksackSet {
+    pub const EMPTY: RucksackSet = RucksackSet(0);
+
+    pub fn insert(&mut self, slot: u8) {
+        self.0 |= 1 << slot;
+    }
+
+    pub fn contains(self, slot: u8) -> bool {
+        self.0 & 1 << slot != 0
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        RucksackSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        RucksackSet(self.0 | other.0)
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Slots of the items, increasing.
+    pub fn slots(self) -> impl Iterator<Item = u8> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            let slot = (bits != 0).then(|| bits.trailing_zeros() as u8)?;
+            bits &= bits - 1;
+            Some(slot)
+        })
+    }
+}
+
+impl BitAnd for RucksackSet {
+    type Output = Self;
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl BitOr for RucksackSet {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard() {
+        let table = PriorityTable::standard();
+        assert_eq!(table.priority('a'), Some(1));
+        assert_eq!(table.priority('Z'), Some(52));
+        assert_eq!(table.priority('é'), None);
+        assert_eq!(table.all().len(), 52);
+    }
+
+    #[test]
+    fn test_parse() {
+        let table = PriorityTable::parse("# Greek\nα-γ 1\nωx-z 10\n").unwrap();
+        assert_eq!(
+            ['α', 'β', 'γ', 'ω', 'x', 'z'].map(|c| table.priority(c).unwrap()),
+            [1, 2, 3, 10, 11, 13]
+        );
+
+        let e = PriorityTable::parse("a-c 1\nxbz 5").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.message.as_str()),
+            (2, 2, "duplicate item")
+        );
+        let e = PriorityTable::parse("z-a 1").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 1, "z-a"));
+        let e = PriorityTable::parse("abc").unwrap_err();
+        assert_eq!(e.message, "expected items and a priority");
+        assert!(PriorityTable::parse("\u{100}-\u{140} 1").is_err());
+
+        let table = PriorityTable::parse("a 4294967295").unwrap();
+        assert_eq!(table.priority('a'), Some(u32::MAX));
+        let e = PriorityTable::parse("a 1\nbc 4294967295").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.message.as_str()),
+            (2, 2, "priority too large")
+        );
+    }
+
+    #[test]
+    fn test_rucksack_set() {
+        let table = PriorityTable::standard();
+        let set = table.set("abcAab").unwrap();
+        assert_eq!(set.len(), 4);
+        assert!(set.contains(table.slot('A').unwrap()));
+        assert!(!set.contains(table.slot('B').unwrap()));
+        assert!(table.items(set).eq(['a', 'b', 'c', 'A']));
+
+        let other = table.set("cZ").unwrap();
+        assert!(table.items(set & other).eq(['c']));
+        assert_eq!((set | other).len(), 5);
+        assert!((set & RucksackSet::EMPTY).is_empty());
+        assert_eq!(table.set("ab;"), Err(';'));
+    }
+
+    #[test]
+    fn test_set_items() {
+        let table = PriorityTable::parse("a-c 1\nαβ 10").unwrap();
+        let set = table.set("cβa").unwrap();
+        assert!(table.contains(set, 'β') && !table.contains(set, 'b'));
+        assert!(!table.contains(set, 'z'));
+        assert!(table.priorities(set).eq([1, 3, 11]));
+        assert!(table
+            .priorities(table.all() & set)
+            .eq(table.priorities(set)));
+    }
+}
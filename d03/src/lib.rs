@@ -0,0 +1,357 @@
+use std::fmt;
+
+use aoc_common::{Answer, Example, Format, ParseError, Reporting, Result, Solver, Streaming};
+
+mod items;
+pub mod report;
+
+pub use items::{PriorityTable, RucksackSet};
+
+pub struct Day03;
+
+/// Number of elves sharing a badge.
+pub const GROUP_SIZE: usize = 3;
+
+/// Why a rucksack or a group has no priority.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RucksackError {
+    UnknownItem(char),
+    /// The compartments can't have as many items.
+    OddLength(usize),
+    NoCommonItem,
+    /// Items in common, by increasing priority.
+    SeveralCommonItems(Vec<char>),
+}
+
+impl fmt::Display for RucksackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownItem(c) => write!(f, "item without a priority: {c:?}"),
+            Self::OddLength(n) => write!(f, "odd number of items: {n}"),
+            Self::NoCommonItem => write!(f, "no item in common"),
+            Self::SeveralCommonItems(items) => {
+                let items: String = items.iter().collect();
+                write!(f, "several items in common: {items:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RucksackError {}
+
+/// Checks that a rucksack only contains items of the puzzle.
+pub fn parse_rucksack(line: &str) -> std::result::Result<String, ParseError> {
+    let line = line.trim_end();
+    PriorityTable::standard().check(line)?;
+    Ok(line.to_string())
+}
+
+/// The items of `table` found in every one of `rucksacks`, by increasing priority.
+pub fn shared_items(
+    table: &PriorityTable,
+    rucksacks: &[&str],
+) -> std::result::Result<Vec<char>, RucksackError> {
+    let common = rucksacks.iter().try_fold(table.all(), |common, items| {
+        Ok(common & table.set(items).map_err(RucksackError::UnknownItem)?)
+    })?;
+
+    let mut items: Vec<_> = table.items(common).collect();
+    items.sort_by_key(|&c| table.priority(c));
+    Ok(items)
+}
+
+/// Priority of the single item shared by `rucksacks`.
+fn single_priority(
+    table: &PriorityTable,
+    rucksacks: &[&str],
+) -> std::result::Result<u32, RucksackError> {
+    let items = shared_items(table, rucksacks)?;
+    match items[..] {
+        [item] => Ok(table
+            .priority(item)
+            .expect("Items of the table have a priority")),
+        [] => Err(RucksackError::NoCommonItem),
+        _ => Err(RucksackError::SeveralCommonItems(items)),
+    }
+}
+
+/// The two compartments of a rucksack, of as many items each.
+pub fn compartments(line: &str) -> std::result::Result<(&str, &str), RucksackError> {
+    let len = line.chars().count();
+    if !len.is_multiple_of(2) {
+        return Err(RucksackError::OddLength(len));
+    }
+
+    let mid = line
+        .char_indices()
+        .nth(len / 2)
+        .map_or(line.len(), |(i, _)| i);
+    Ok(line.split_at(mid))
+}
+
+/// Priority of the single item found in both compartments of a rucksack.
+pub fn get_priority_line(
+    table: &PriorityTable,
+    line: &str,
+) -> std::result::Result<u32, RucksackError> {
+    let (first, second) = compartments(line)?;
+    single_priority(table, &[first, second])
+}
+
+/// Priority of the single item found in every rucksack of a group, of any size.
+pub fn get_priority_group(
+    table: &PriorityTable,
+    group: &[&str],
+) -> std::result::Result<u32, RucksackError> {
+    single_priority(table, group)
+}
+
+/// A rucksack of an [`Inventory`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rucksack {
+    /// Line of the input, starting at 1.
+    pub line: usize,
+    pub items: String,
+}
+
+impl Rucksack {
+    /// An error pointing at the whole rucksack.
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::new(&self.items, &self.items, message).on_line(self.line)
+    }
+}
+
+/// Rucksacks checked to only hold items of a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inventory {
+    pub table: PriorityTable,
+    pub rucksacks: Vec<Rucksack>,
+}
+
+impl Inventory {
+    /// One rucksack per non-blank line of `content`.
+    pub fn parse(table: PriorityTable, content: &str) -> std::result::Result<Self, ParseError> {
+        let mut rucksacks = Vec::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim_end();
+            if line.trim().is_empty() {
+                continue;
+            }
+            table.check(line).map_err(|e| e.within(content, line))?;
+            rucksacks.push(Rucksack {
+                line: i + 1,
+                items: line.to_string(),
+            });
+        }
+        Ok(Inventory { table, rucksacks })
+    }
+
+    /// Sum of the priorities of the item in both compartments of each rucksack.
+    pub fn line_priorities(&self) -> std::result::Result<u32, ParseError> {
+        let mut sum = 0;
+        for rucksack in &self.rucksacks {
+            sum += get_priority_line(&self.table, &rucksack.items)
+                .map_err(|e| rucksack.error(e.to_string()))?;
+        }
+        Ok(sum)
+    }
+
+    /// Sum of the priorities of the badge of each group. An incomplete last group is
+    /// ignored.
+    pub fn group_priorities(&self) -> std::result::Result<u32, ParseError> {
+        let mut sum = 0;
+        for group in self.rucksacks.chunks_exact(GROUP_SIZE) {
+            let items: Vec<_> = group.iter().map(|r| r.items.as_str()).collect();
+            sum += get_priority_group(&self.table, &items)
+                .map_err(|e| group[0].error(format!("group starting here: {e}")))?;
+        }
+        Ok(sum)
+    }
+}
+
+/// Running sums of a streamed list of rucksacks.
+#[derive(Clone, Debug, Default)]
+pub struct Priorities {
+    lines: u32,
+    groups: u32,
+    /// Rucksacks of the group being read.
+    group: Vec<String>,
+}
+
+impl Streaming for Day03 {
+    type State = Priorities;
+
+    fn feed(sums: &mut Priorities, line: &str) -> std::result::Result<(), ParseError> {
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+
+        let table = PriorityTable::standard();
+        let rucksack = parse_rucksack(line)?;
+        sums.lines += get_priority_line(table, &rucksack)
+            .map_err(|e| ParseError::new(&rucksack, &rucksack, e.to_string()))?;
+
+        sums.group.push(rucksack);
+        if sums.group.len() == GROUP_SIZE {
+            let group: Vec<_> = sums.group.iter().map(String::as_str).collect();
+            // Located on the line being fed, the last of the group
+            let last = group[GROUP_SIZE - 1];
+            sums.groups += get_priority_group(table, &group)
+                .map_err(|e| ParseError::new(last, last, format!("group ending here: {e}")))?;
+            sums.group.clear();
+        }
+        Ok(())
+    }
+
+    fn part1(sums: &Priorities) -> Result<Answer> {
+        Ok(sums.lines.into())
+    }
+
+    /// An incomplete last group is ignored.
+    fn part2(sums: &Priorities) -> Result<Answer> {
+        Ok(sums.groups.into())
+    }
+}
+
+/// An audit of the items shared by compartments and groups, and of how to regroup the
+/// elves.
+impl Reporting for Day03 {
+    fn report(input: &str, format: Format) -> Result<String> {
+        let inventory = Day03::parse(input)?;
+        Ok(report::Report::new(&inventory).format(format))
+    }
+}
+
+impl Solver for Day03 {
+    const DAY: u8 = 3;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+",
+        part1: "157",
+        part2: "70",
+    };
+
+    /// Rucksacks of the standard table: other tables go through [`Inventory::parse`].
+    type Input = Inventory;
+
+    fn parse(input: &str) -> Result<Inventory> {
+        Ok(Inventory::parse(PriorityTable::standard().clone(), input)?)
+    }
+
+    fn part1(inventory: &Inventory) -> Result<Answer> {
+        Ok(inventory.line_priorities()?.into())
+    }
+
+    fn part2(inventory: &Inventory) -> Result<Answer> {
+        Ok(inventory.group_priorities()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(line: &str) -> std::result::Result<u32, RucksackError> {
+        get_priority_line(PriorityTable::standard(), line)
+    }
+
+    fn group(group: &[&str]) -> std::result::Result<u32, RucksackError> {
+        get_priority_group(PriorityTable::standard(), group)
+    }
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day03>();
+        aoc_common::check_stream_example::<Day03>();
+    }
+
+    #[test]
+    fn test_line_matches() {
+        assert_eq!(line("aa"), Ok(1));
+        assert_eq!(line("abcZdZtr"), Ok(52));
+    }
+
+    #[test]
+    fn test_line_errors() {
+        assert_eq!(line("ab"), Err(RucksackError::NoCommonItem));
+        assert_eq!(line(";;"), Err(RucksackError::UnknownItem(';')));
+        assert_eq!(line("abc"), Err(RucksackError::OddLength(3)));
+        assert_eq!(
+            line("aBcaBd"),
+            Err(RucksackError::SeveralCommonItems(vec!['a', 'B']))
+        );
+    }
+
+    #[test]
+    fn test_unicode() {
+        let table = PriorityTable::parse("αβγδ 1\na-z 5").unwrap();
+        // Split by items, not bytes
+        assert_eq!(get_priority_line(&table, "aαbα"), Ok(1));
+        assert_eq!(compartments("aαbα"), Ok(("aα", "bα")));
+        assert_eq!(get_priority_group(&table, &["αb", "γb", "bδ"]), Ok(6));
+    }
+
+    #[test]
+    fn test_parse_rucksack() {
+        assert_eq!(parse_rucksack("abCD").unwrap(), "abCD");
+        let e = parse_rucksack("ab;D").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (3, ";"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = Day03::parse("abca\nab;D\n").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Parse error: item without a priority at line 2, column 3: \";\""
+        );
+
+        let input = Day03::parse("abca\nabcd\nxyzx\n").unwrap();
+        let e = <Day03 as Solver>::part1(&input).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Parse error: no item in common at line 2, column 1: \"abcd\""
+        );
+    }
+
+    #[test]
+    fn test_inventory() {
+        let table = PriorityTable::parse("αβγδ 1\na-z 5").unwrap();
+        let inventory = Inventory::parse(table, "aαbα\n\nγbbδ\nbαbβ\n").unwrap();
+        assert_eq!(inventory.rucksacks[1].line, 3);
+        assert_eq!(inventory.line_priorities(), Ok(13));
+        assert_eq!(inventory.group_priorities(), Ok(6));
+
+        let e = Inventory::parse(inventory.table, "aα\nAα").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "A"));
+
+        let input = Day03::parse("abcd\nabca\nabca\n").unwrap();
+        let e = <Day03 as Solver>::part2(&input).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Parse error: group starting here: several items in common: \"abc\" at line 1, column 1: \"abcd\""
+        );
+    }
+
+    #[test]
+    fn test_group_matches() {
+        assert_eq!(group(&["milder", "MILDERKeBABS", "kebabs"]), Ok(5));
+        assert_eq!(
+            group(&["BritishBroadcastingCorporation", "MILDERKEBABS", "hexBF"]),
+            Ok(28)
+        );
+        assert_eq!(group(&["abc", "bxd"]), Ok(2));
+        assert_eq!(group(&["abc", "bcd", "cde", "xyzc"]), Ok(3));
+        assert_eq!(
+            group(&["abc", "bcd", "bcx"]),
+            Err(RucksackError::SeveralCommonItems(vec!['b', 'c']))
+        );
+    }
+}
@@ -0,0 +1,390 @@
+//! Auditing of malformed inventories: every shared item rather than the first one.
+
+use aoc_common::{Answer, Format};
+
+use crate::{
+    compartments, shared_items, Inventory, PriorityTable, RucksackError, RucksackSet, GROUP_SIZE,
+};
+
+/// An item found in both compartments of a rucksack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommonItem {
+    pub item: char,
+    /// Occurrences in the first compartment.
+    pub first: usize,
+    /// Occurrences in the second compartment.
+    pub second: usize,
+}
+
+/// Every item common to both compartments of `line`, by increasing priority.
+pub fn common_items(table: &PriorityTable, line: &str) -> Result<Vec<CommonItem>, RucksackError> {
+    let (first, second) = compartments(line)?;
+    Ok(shared_items(table, &[first, second])?
+        .into_iter()
+        .map(|item| CommonItem {
+            item,
+            first: first.matches(item).count(),
+            second: second.matches(item).count(),
+        })
+        .collect())
+}
+
+/// Badge candidates of a group of consecutive elves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GroupReport {
+    /// Index of the first elf of the group.
+    pub first: usize,
+    pub candidates: Result<Vec<char>, RucksackError>,
+}
+
+impl GroupReport {
+    /// The badge of the group, if it has a single candidate.
+    pub fn badge(&self) -> Option<char> {
+        match self.candidates.as_deref() {
+            Ok(&[badge]) => Some(badge),
+            _ => None,
+        }
+    }
+
+    /// Whether the group has no badge or several candidates.
+    pub fn is_flagged(&self) -> bool {
+        self.badge().is_none()
+    }
+}
+
+/// Audit of a list of rucksacks, one per elf.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    /// Items common to both compartments, for each rucksack.
+    pub rucksacks: Vec<Result<Vec<CommonItem>, RucksackError>>,
+    /// Complete groups only: the last elves are left out if they can't make one.
+    pub groups: Vec<GroupReport>,
+    /// New groups for the elves of the flagged groups, each with a single badge, the
+    /// others keeping theirs. Empty if no group is flagged, `None` if there's no such
+    /// regrouping.
+    pub regrouping: Option<Vec<[usize; GROUP_SIZE]>>,
+}
+
+impl Report {
+    /// Elves are the rucksacks of `inventory`, in order.
+    pub fn new(inventory: &Inventory) -> Self {
+        let table = &inventory.table;
+        let rucksacks: Vec<_> = inventory
+            .rucksacks
+            .iter()
+            .map(|r| r.items.as_str())
+            .collect();
+        let groups: Vec<_> = rucksacks
+            .chunks_exact(GROUP_SIZE)
+            .enumerate()
+            .map(|(i, group)| GroupReport {
+                first: i * GROUP_SIZE,
+                candidates: shared_items(table, group),
+            })
+            .collect();
+
+        let flagged: Vec<_> = groups
+            .iter()
+            .filter(|g| g.is_flagged())
+            .flat_map(|g| g.first..g.first + GROUP_SIZE)
+            .collect();
+        // Elves carrying unknown items can't be given a badge
+        let sets: Option<Vec<_>> = flagged
+            .iter()
+            .map(|&elf| table.set(rucksacks[elf]).ok())
+            .collect();
+        let regrouping = sets.and_then(|sets| {
+            let groups = regroup(&sets)?;
+            Some(groups.into_iter().map(|g| g.map(|i| flagged[i])).collect())
+        });
+
+        Report {
+            rucksacks: rucksacks
+                .iter()
+                .map(|line| common_items(table, line))
+                .collect(),
+            groups,
+            regrouping,
+        }
+    }
+
+    /// Elves and groups are numbered from 1. Rucksacks and groups without a single
+    /// shared item are flagged.
+    pub fn format(&self, format: Format) -> String {
+        let json = |s: String| Answer::Text(s).to_json();
+        let elves = |group: &[usize]| group.iter().map(|elf| elf + 1).collect::<Vec<_>>();
+
+        let mut lines = Vec::new();
+        if format == Format::Text {
+            lines.push(format!("{:>5}  Common items", "Elf"));
+        }
+        for (elf, common) in self.rucksacks.iter().enumerate() {
+            let elf = elf + 1;
+            lines.push(match (format, common) {
+                (Format::Text, Ok(common)) => {
+                    let items: Vec<_> = common
+                        .iter()
+                        .map(|c| format!("{} ({}+{})", c.item, c.first, c.second))
+                        .collect();
+                    let items = if items.is_empty() {
+                        "none".to_string()
+                    } else {
+                        items.join(", ")
+                    };
+                    let flag = if common.len() == 1 {
+                        ""
+                    } else {
+                        "  <- flagged"
+                    };
+                    format!("{elf:>5}  {items}{flag}")
+                }
+                (Format::Text, Err(e)) => format!("{elf:>5}  {e}  <- flagged"),
+                (Format::Json, Ok(common)) => {
+                    let items: Vec<_> = common
+                        .iter()
+                        .map(|c| {
+                            format!(
+                                r#"{{"item":{},"first":{},"second":{}}}"#,
+                                json(c.item.to_string()),
+                                c.first,
+                                c.second
+                            )
+                        })
+                        .collect();
+                    format!(r#"{{"elf":{elf},"common":[{}]}}"#, items.join(","))
+                }
+                (Format::Json, Err(e)) => {
+                    format!(r#"{{"elf":{elf},"error":{}}}"#, json(e.to_string()))
+                }
+            });
+        }
+
+        if format == Format::Text {
+            lines.push(format!("{:>5}  {:<9}  Badge candidates", "Group", "Elves"));
+        }
+        for (i, group) in self.groups.iter().enumerate() {
+            let members = elves(&(group.first..group.first + GROUP_SIZE).collect::<Vec<_>>());
+            lines.push(match (format, &group.candidates) {
+                (Format::Text, candidates) => {
+                    let range = format!("{}-{}", members[0], members[members.len() - 1]);
+                    let candidates = match candidates {
+                        Ok(items) if items.is_empty() => "none".to_string(),
+                        Ok(items) => items
+                            .iter()
+                            .map(char::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        Err(e) => e.to_string(),
+                    };
+                    let flag = if group.is_flagged() {
+                        "  <- flagged"
+                    } else {
+                        ""
+                    };
+                    format!("{:>5}  {range:<9}  {candidates}{flag}", i + 1)
+                }
+                (Format::Json, Ok(items)) => {
+                    let items: Vec<_> = items.iter().map(|c| json(c.to_string())).collect();
+                    format!(
+                        r#"{{"group":{},"elves":{members:?},"candidates":[{}]}}"#,
+                        i + 1,
+                        items.join(",")
+                    )
+                }
+                (Format::Json, Err(e)) => format!(
+                    r#"{{"group":{},"elves":{members:?},"error":{}}}"#,
+                    i + 1,
+                    json(e.to_string())
+                ),
+            });
+        }
+
+        let groups: Option<Vec<_>> = self
+            .regrouping
+            .as_ref()
+            .map(|groups| groups.iter().map(|g| elves(g)).collect());
+        lines.push(match (format, groups) {
+            (Format::Text, Some(groups)) if groups.is_empty() => {
+                "Every group has a badge".to_string()
+            }
+            (Format::Text, Some(groups)) => {
+                let groups: Vec<_> = groups
+                    .iter()
+                    .map(|g| g.iter().map(usize::to_string).collect::<Vec<_>>().join(" "))
+                    .collect();
+                format!("Regroup the elves: {}", groups.join(", "))
+            }
+            (Format::Text, None) => "No regrouping gives every group a badge".to_string(),
+            (Format::Json, Some(groups)) => format!(r#"{{"regrouping":{groups:?}}}"#),
+            (Format::Json, None) => r#"{"regrouping":null}"#.to_string(),
+        });
+
+        lines.join("\n")
+    }
+}
+
+/// Splits `sets` into groups whose items have a single one in common, as indices of `sets`.
+///
+/// A backtracking search, exponential in the worst case: only meant for the few
+/// rucksacks of the flagged groups.
+pub fn regroup(sets: &[RucksackSet]) -> Option<Vec<[usize; GROUP_SIZE]>> {
+    if !sets.len().is_multiple_of(GROUP_SIZE) {
+        return None;
+    }
+
+    let mut free = vec![true; sets.len()];
+    let mut groups = Vec::with_capacity(sets.len() / GROUP_SIZE);
+    search(sets, &mut free, &mut groups).then_some(groups)
+}
+
+/// Groups the free sets, extending `groups`, or leaves both as they were.
+fn search(sets: &[RucksackSet], free: &mut [bool], groups: &mut Vec<[usize; GROUP_SIZE]>) -> bool {
+    // The first free set must be in some group: only those holding it are tried
+    let Some(first) = free.iter().position(|&f| f) else {
+        return true;
+    };
+
+    free[first] = false;
+    let mut group = [first; GROUP_SIZE];
+    if complete(sets, free, groups, &mut group, 1, sets[first]) {
+        return true;
+    }
+    free[first] = true;
+    false
+}
+
+/// Fills `group` from position `len`, with free sets after the previous member.
+fn complete(
+    sets: &[RucksackSet],
+    free: &mut [bool],
+    groups: &mut Vec<[usize; GROUP_SIZE]>,
+    group: &mut [usize; GROUP_SIZE],
+    len: usize,
+    common: RucksackSet,
+) -> bool {
+    if len == GROUP_SIZE {
+        if common.len() != 1 {
+            return false;
+        }
+        groups.push(*group);
+        if search(sets, free, groups) {
+            return true;
+        }
+        groups.pop();
+        return false;
+    }
+
+    for next in group[len - 1] + 1..sets.len() {
+        let common = common & sets[next];
+        if !free[next] || common.is_empty() {
+            continue;
+        }
+
+        free[next] = false;
+        group[len] = next;
+        if complete(sets, free, groups, group, len + 1, common) {
+            return true;
+        }
+        free[next] = true;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day03, Solver};
+
+    fn report_of(rucksacks: &[&str]) -> Report {
+        let table = PriorityTable::standard().clone();
+        Report::new(&Inventory::parse(table, &rucksacks.join("\n")).unwrap())
+    }
+
+    #[test]
+    fn test_common_items() {
+        let table = PriorityTable::standard();
+        assert_eq!(
+            common_items(table, "aBadcaBb"),
+            Ok(vec![
+                CommonItem {
+                    item: 'a',
+                    first: 2,
+                    second: 1
+                },
+                CommonItem {
+                    item: 'B',
+                    first: 1,
+                    second: 1
+                },
+            ])
+        );
+        assert_eq!(common_items(table, "ab"), Ok(vec![]));
+        assert_eq!(common_items(table, "abc"), Err(RucksackError::OddLength(3)));
+    }
+
+    #[test]
+    fn test_example() {
+        let report = Report::new(&Day03::parse(Day03::EXAMPLE.input).unwrap());
+
+        assert_eq!(report.rucksacks.len(), 6);
+        assert!(report
+            .rucksacks
+            .iter()
+            .all(|r| r.as_ref().unwrap().len() == 1));
+        let badges: Vec<_> = report.groups.iter().map(GroupReport::badge).collect();
+        assert_eq!(badges, [Some('r'), Some('Z')]);
+        assert_eq!(report.regrouping, Some(vec![]));
+    }
+
+    #[test]
+    fn test_regrouping() {
+        // The first groups share nothing and "ab", while "xa", "abxx" and "abyy" would
+        // only share "a", and "yb", "zb" and "abzc" only "b"
+        let rucksacks = ["xa", "yb", "zb", "abxx", "abyy", "abzc", "cd", "ce", "cf"];
+        let report = report_of(&rucksacks);
+
+        let candidates: Vec<_> = report.groups.iter().map(|g| g.candidates.clone()).collect();
+        assert_eq!(candidates, [Ok(vec![]), Ok(vec!['a', 'b']), Ok(vec!['c'])]);
+        assert_eq!(report.regrouping, Some(vec![[0, 3, 4], [1, 2, 5]]));
+    }
+
+    #[test]
+    fn test_no_regrouping() {
+        let table = PriorityTable::standard();
+        let report = report_of(&["ab", "ab", "ab", "a", "b"]);
+        assert!(report.groups[0].is_flagged());
+        assert_eq!(report.regrouping, None);
+
+        let sets = ["ab", "ac", "bc"].map(|s| table.set(s).unwrap());
+        assert_eq!(regroup(&sets), None);
+        assert_eq!(regroup(&sets[..2]), None);
+    }
+
+    #[test]
+    fn test_format() {
+        let report = report_of(&["xa", "yb", "zb", "abxx", "abyy", "abzc", "ccd"]);
+        assert_eq!(
+            report.format(Format::Text),
+            "  Elf  Common items
+    1  none  <- flagged
+    2  none  <- flagged
+    3  none  <- flagged
+    4  none  <- flagged
+    5  none  <- flagged
+    6  none  <- flagged
+    7  odd number of items: 3  <- flagged
+Group  Elves      Badge candidates
+    1  1-3        none  <- flagged
+    2  4-6        a, b  <- flagged
+Regroup the elves: 1 4 5, 2 3 6"
+        );
+
+        let report = report_of(&["aa", "abc"]);
+        assert_eq!(
+            report.format(Format::Json),
+            r#"{"elf":1,"common":[{"item":"a","first":1,"second":1}]}
+{"elf":2,"error":"odd number of items: 3"}
+{"regrouping":[]}"#
+        );
+    }
+}
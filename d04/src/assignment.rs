@@ -0,0 +1,179 @@
+use std::{fmt, ops::RangeInclusive};
+
+/// Sections assigned to an elf, bounds included. Never empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Assignment {
+    start: u32,
+    end: u32,
+}
+
+impl Assignment {
+    /// The sections from `start` to `end`, or `None` if `end` comes first.
+    pub fn new(start: u32, end: u32) -> Option<Self> {
+        (start <= end).then_some(Assignment { start, end })
+    }
+
+    /// A single section.
+    pub fn section(section: u32) -> Self {
+        Assignment {
+            start: section,
+            end: section,
+        }
+    }
+
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
+    /// Number of sections.
+    pub fn len(&self) -> u64 {
+        u64::from(self.end - self.start) + 1
+    }
+
+    /// Always false: an assignment has at least one section.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn contains_section(&self, section: u32) -> bool {
+        self.start <= section && section <= self.end
+    }
+
+    /// Whether every section of `other` is in `self`.
+    pub fn contains(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Sections in both, if any.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Assignment::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Sections in either, if they make a single range: the assignments overlap or
+    /// are next to each other.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        (second.start <= first.end.saturating_add(1)).then(|| self.hull(other))
+    }
+
+    /// The smallest assignment containing both.
+    pub fn hull(&self, other: &Self) -> Self {
+        Assignment {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    /// Sections of `self` which aren't in `other`: none, or the ranges before and
+    /// after it.
+    pub fn difference(&self, other: &Self) -> impl Iterator<Item = Self> {
+        if !self.overlaps(other) {
+            return [Some(*self), None].into_iter().flatten();
+        }
+
+        let before = other
+            .start
+            .checked_sub(1)
+            .and_then(|end| Assignment::new(self.start, end));
+        let after = other
+            .end
+            .checked_add(1)
+            .and_then(|start| Assignment::new(start, self.end));
+        [before, after].into_iter().flatten()
+    }
+
+    pub fn sections(&self) -> RangeInclusive<u32> {
+        self.start..=self.end
+    }
+}
+
+impl From<Assignment> for RangeInclusive<u32> {
+    fn from(assignment: Assignment) -> Self {
+        assignment.sections()
+    }
+}
+
+/// As in the puzzle input: `2-4`.
+impl fmt::Display for Assignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: u32, end: u32) -> Assignment {
+        Assignment::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Assignment::new(4, 2), None);
+        assert_eq!(range(3, 3), Assignment::section(3));
+        assert_eq!(range(2, 4).len(), 3);
+        assert_eq!(range(0, u32::MAX).len(), 1 << 32);
+        assert!(!range(3, 3).is_empty());
+        assert_eq!(range(2, 4).to_string(), "2-4");
+    }
+
+    #[test]
+    fn test_predicates() {
+        assert!(range(2, 8).contains(&range(3, 7)));
+        assert!(range(2, 8).contains(&range(2, 8)));
+        assert!(!range(3, 7).contains(&range(2, 8)));
+        assert!(range(2, 4).contains_section(4));
+        assert!(!range(2, 4).contains_section(5));
+
+        assert!(range(5, 7).overlaps(&range(7, 9)));
+        assert!(range(7, 9).overlaps(&range(5, 7)));
+        assert!(!range(2, 3).overlaps(&range(4, 5)));
+    }
+
+    #[test]
+    fn test_intersection_union() {
+        assert_eq!(range(2, 6).intersection(&range(4, 8)), Some(range(4, 6)));
+        assert_eq!(range(2, 3).intersection(&range(4, 5)), None);
+
+        assert_eq!(range(4, 8).union(&range(2, 6)), Some(range(2, 8)));
+        // Adjacent ranges make a single one
+        assert_eq!(range(2, 3).union(&range(4, 5)), Some(range(2, 5)));
+        assert_eq!(range(2, 3).union(&range(5, 6)), None);
+        assert_eq!(range(2, 3).hull(&range(5, 6)), range(2, 6));
+        assert_eq!(
+            range(0, u32::MAX).union(&range(u32::MAX, u32::MAX)),
+            Some(range(0, u32::MAX))
+        );
+    }
+
+    #[test]
+    fn test_difference() {
+        let difference = |a: Assignment, b| a.difference(&b).collect::<Vec<_>>();
+
+        assert_eq!(
+            difference(range(2, 8), range(4, 5)),
+            [range(2, 3), range(6, 8)]
+        );
+        assert_eq!(difference(range(2, 8), range(0, 5)), [range(6, 8)]);
+        assert_eq!(difference(range(2, 8), range(5, 9)), [range(2, 4)]);
+        assert_eq!(difference(range(2, 8), range(1, 9)), []);
+        assert_eq!(difference(range(2, 3), range(5, 9)), [range(2, 3)]);
+        assert_eq!(
+            difference(range(0, u32::MAX), range(0, 0)),
+            [range(1, u32::MAX)]
+        );
+    }
+}
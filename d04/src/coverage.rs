@@ -0,0 +1,242 @@
+//! Assignments of any number of elves, analyzed by sweeping over their bounds.
+
+use std::fmt::Write;
+
+use aoc_common::Format;
+
+use crate::Assignment;
+
+/// How the sections between the first and the last assigned ones are covered.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Coverage {
+    pub elves: usize,
+    /// Sections no elf covers, between the first and the last assigned ones.
+    pub uncovered: Vec<Assignment>,
+    /// Sections covered by more than one elf.
+    pub shared: Vec<Assignment>,
+    /// Most elves covering a single section.
+    pub max_depth: usize,
+    /// Indices of the most elves which can be removed together without changing the
+    /// sections covered, increasing. The others are a smallest cover.
+    pub removable: Vec<usize>,
+}
+
+impl Coverage {
+    pub fn of(assignments: &[Assignment]) -> Self {
+        // Depth changes at the start of each assignment and right after its end
+        let mut events: Vec<(u64, isize)> = assignments
+            .iter()
+            .flat_map(|a| [(u64::from(a.start()), 1), (u64::from(a.end()) + 1, -1)])
+            .collect();
+        events.sort_unstable();
+
+        let mut coverage = Coverage {
+            elves: assignments.len(),
+            removable: removable(assignments),
+            ..Coverage::default()
+        };
+        let mut depth = 0;
+        let mut i = 0;
+        while i < events.len() {
+            let position = events[i].0;
+            while i < events.len() && events[i].0 == position {
+                depth += events[i].1;
+                i += 1;
+            }
+            let Some(&(next, _)) = events.get(i) else {
+                break;
+            };
+
+            // Sections from `position` to `next` are covered by `depth` elves
+            let sections =
+                Assignment::new(position as u32, (next - 1) as u32).expect("Events are sorted");
+            match depth {
+                0 => extend(&mut coverage.uncovered, sections),
+                1 => {}
+                _ => extend(&mut coverage.shared, sections),
+            }
+            coverage.max_depth = coverage.max_depth.max(depth as usize);
+        }
+
+        coverage
+    }
+}
+
+/// Adds `sections` after the last of `ranges`, merging them if they're next to each other.
+fn extend(ranges: &mut Vec<Assignment>, sections: Assignment) {
+    match ranges.last_mut() {
+        Some(last) if last.end().checked_add(1) == Some(sections.start()) => {
+            *last = last.hull(&sections);
+        }
+        _ => ranges.push(sections),
+    }
+}
+
+/// The elves left out of a smallest cover, picked greedily: from the first uncovered
+/// section, the elf covering it which reaches the furthest.
+fn removable(assignments: &[Assignment]) -> Vec<usize> {
+    let mut order: Vec<_> = (0..assignments.len()).collect();
+    order.sort_by_key(|&i| assignments[i].start());
+
+    let mut kept = vec![false; assignments.len()];
+    // First section not covered yet by the kept elves
+    let mut next: Option<u64> = None;
+    let mut i = 0;
+    while i < order.len() {
+        let start = assignments[order[i]].start();
+        let from = match next {
+            Some(next) if next > u64::from(start) => next,
+            _ => u64::from(start),
+        };
+
+        // Among the elves starting before it, the one covering the furthest
+        let mut best: Option<usize> = None;
+        while i < order.len() && u64::from(assignments[order[i]].start()) <= from {
+            let elf = order[i];
+            if best.is_none_or(|b| assignments[elf].end() > assignments[b].end()) {
+                best = Some(elf);
+            }
+            i += 1;
+        }
+
+        let best = best.expect("The first elf left starts before");
+        let end = u64::from(assignments[best].end()) + 1;
+        if end > from {
+            kept[best] = true;
+            next = Some(end);
+        }
+    }
+
+    (0..assignments.len()).filter(|&i| !kept[i]).collect()
+}
+
+/// Lines and elves are numbered from 1.
+pub fn format(lines: &[Coverage], format: Format) -> String {
+    let elves = |elves: &[usize]| elves.iter().map(|elf| elf + 1).collect::<Vec<_>>();
+
+    match format {
+        Format::Text => {
+            let ranges = |ranges: &[Assignment]| match ranges {
+                [] => "-".to_string(),
+                _ => ranges
+                    .iter()
+                    .map(Assignment::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            };
+            let mut out = format!(
+                "{:>4}  {:>5}  {:>5}  {:<12}  {:<12}  Removable",
+                "Line", "Elves", "Depth", "Uncovered", "Shared"
+            );
+            for (i, c) in lines.iter().enumerate() {
+                let removable = match c.removable.as_slice() {
+                    [] => "-".to_string(),
+                    removable => elves(removable)
+                        .iter()
+                        .map(usize::to_string)
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                };
+                write!(
+                    out,
+                    "\n{:>4}  {:>5}  {:>5}  {:<12}  {:<12}  {removable}",
+                    i + 1,
+                    c.elves,
+                    c.max_depth,
+                    ranges(&c.uncovered),
+                    ranges(&c.shared)
+                )
+                .unwrap();
+            }
+            out
+        }
+        Format::Json => {
+            let ranges = |ranges: &[Assignment]| {
+                let ranges: Vec<_> = ranges.iter().map(|r| format!(r#""{r}""#)).collect();
+                format!("[{}]", ranges.join(","))
+            };
+            lines
+                .iter()
+                .enumerate()
+                .map(|(i, c)| {
+                    format!(
+                        r#"{{"line":{},"elves":{},"depth":{},"uncovered":{},"shared":{},"removable":{:?}}}"#,
+                        i + 1,
+                        c.elves,
+                        c.max_depth,
+                        ranges(&c.uncovered),
+                        ranges(&c.shared),
+                        elves(&c.removable)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode_assignments;
+
+    fn ranges(list: &[(u32, u32)]) -> Vec<Assignment> {
+        list.iter()
+            .map(|&(start, end)| Assignment::new(start, end).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_coverage() {
+        let coverage = Coverage::of(&ranges(&[(2, 4), (8, 9), (3, 6), (4, 5), (12, 12)]));
+        assert_eq!(
+            coverage,
+            Coverage {
+                elves: 5,
+                uncovered: ranges(&[(7, 7), (10, 11)]),
+                shared: ranges(&[(3, 5)]),
+                max_depth: 3,
+                removable: vec![3],
+            }
+        );
+    }
+
+    #[test]
+    fn test_removable() {
+        // 1-3 and 4-9 alone cover 1-9
+        let coverage = Coverage::of(&ranges(&[(1, 2), (2, 3), (4, 6), (1, 3), (4, 9), (5, 9)]));
+        assert_eq!(coverage.removable, [0, 1, 2, 5]);
+        assert_eq!(coverage.uncovered, []);
+        assert_eq!(coverage.shared, ranges(&[(1, 9)]));
+
+        // Identical assignments: the first one is kept
+        assert_eq!(Coverage::of(&ranges(&[(1, 5), (1, 5)])).removable, [1]);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let coverage = Coverage::of(&ranges(&[(0, u32::MAX), (u32::MAX, u32::MAX)]));
+        assert_eq!(coverage.shared, ranges(&[(u32::MAX, u32::MAX)]));
+        assert_eq!(coverage.max_depth, 2);
+
+        assert_eq!(Coverage::of(&[]), Coverage::default());
+    }
+
+    #[test]
+    fn test_format() {
+        let lines = ["2-4,8-9,3-6,4-5,12-12", "6-6"]
+            .map(|line| Coverage::of(&decode_assignments(line).unwrap()));
+
+        assert_eq!(
+            format(&lines, Format::Text),
+            "\
+Line  Elves  Depth  Uncovered     Shared        Removable
+   1      5      3  7-7 10-11     3-5           4
+   2      1      1  -             -             -"
+        );
+        assert_eq!(
+            format(&lines[..1], Format::Json),
+            r#"{"line":1,"elves":5,"depth":3,"uncovered":["7-7","10-11"],"shared":["3-5"],"removable":[4]}"#
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Queries over many assignments, whatever line they come from.
+
+use crate::{Assignment, Pair};
+
+/// Assignments sorted by start, with the furthest end of each subtree of a balanced
+/// tree over them.
+///
+/// Both queries come down to the assignments of a prefix ending at some section: the
+/// tree skips the subtrees ending before another. They take O((k + 1) log n) to report
+/// k assignments, each found at the end of its own path from the root.
+#[derive(Clone, Debug)]
+pub struct AssignmentIndex {
+    /// Assignments and their index in the input, by increasing start.
+    entries: Vec<(Assignment, usize)>,
+    /// Furthest end of each node, the children of node `i` being `2i + 1` and `2i + 2`.
+    max_end: Vec<u32>,
+}
+
+impl AssignmentIndex {
+    /// Queries return indices into `assignments`.
+    pub fn new(assignments: &[Assignment]) -> Self {
+        let mut entries: Vec<_> = assignments.iter().copied().zip(0..).collect();
+        entries.sort_unstable_by_key(|&(a, i)| (a.start(), i));
+
+        let mut index = AssignmentIndex {
+            max_end: vec![0; 4 * entries.len().max(1)],
+            entries,
+        };
+        if !index.entries.is_empty() {
+            index.build(0, 0, index.entries.len());
+        }
+        index
+    }
+
+    /// Indexes both assignments of each pair: those of pair `i` are `2i` and `2i + 1`.
+    pub fn from_pairs(pairs: &[Pair]) -> Self {
+        let assignments: Vec<_> = pairs.iter().flat_map(|p| [p.first, p.second]).collect();
+        Self::new(&assignments)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Assignments sharing a section with `query`, by increasing start.
+    pub fn overlapping(&self, query: Assignment) -> Vec<usize> {
+        self.reaching(query.end(), query.start())
+    }
+
+    /// Assignments containing every section of `query`, by increasing start.
+    pub fn containing(&self, query: Assignment) -> Vec<usize> {
+        self.reaching(query.start(), query.end())
+    }
+
+    /// Assignments starting at most at `start` and ending at least at `end`.
+    fn reaching(&self, start: u32, end: u32) -> Vec<usize> {
+        let prefix = self.entries.partition_point(|(a, _)| a.start() <= start);
+        let mut found = Vec::new();
+        if prefix > 0 {
+            self.collect(0, 0, self.entries.len(), prefix, end, &mut found);
+        }
+        found
+    }
+
+    fn build(&mut self, node: usize, lo: usize, hi: usize) -> u32 {
+        let max = if hi - lo == 1 {
+            self.entries[lo].0.end()
+        } else {
+            let mid = (lo + hi) / 2;
+            let left = self.build(2 * node + 1, lo, mid);
+            left.max(self.build(2 * node + 2, mid, hi))
+        };
+        self.max_end[node] = max;
+        max
+    }
+
+    /// Pushes the entries of `node`, covering `lo..hi`, which are before `prefix` and
+    /// end at least at `end`.
+    fn collect(
+        &self,
+        node: usize,
+        lo: usize,
+        hi: usize,
+        prefix: usize,
+        end: u32,
+        found: &mut Vec<usize>,
+    ) {
+        if lo >= prefix || self.max_end[node] < end {
+            return;
+        }
+        if hi - lo == 1 {
+            found.push(self.entries[lo].1);
+            return;
+        }
+
+        let mid = (lo + hi) / 2;
+        self.collect(2 * node + 1, lo, mid, prefix, end, found);
+        self.collect(2 * node + 2, mid, hi, prefix, end, found);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::random::XorShift;
+
+    use super::*;
+    use crate::{decode_line, Day04, Solver};
+
+    fn range(start: u32, end: u32) -> Assignment {
+        Assignment::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn test_example() {
+        let pairs = Day04::parse(Day04::EXAMPLE.input).unwrap();
+        let index = AssignmentIndex::from_pairs(&pairs);
+        assert_eq!(index.len(), 12);
+
+        // 2-4, 2-3, 2-8, 2-6 in the order of their starts
+        assert_eq!(index.overlapping(range(1, 2)), [0, 2, 6, 10]);
+        assert_eq!(index.containing(range(3, 7)), [6, 7]);
+        assert_eq!(index.containing(range(9, 9)), [5]);
+        assert_eq!(index.overlapping(range(10, 20)), []);
+        assert!(index.containing(range(0, 100)).is_empty());
+    }
+
+    #[test]
+    fn test_empty() {
+        let index = AssignmentIndex::new(&[]);
+        assert!(index.is_empty());
+        assert_eq!(index.overlapping(range(0, u32::MAX)), []);
+    }
+
+    #[test]
+    fn test_brute_force() {
+        let mut rng = XorShift::default();
+        let mut next = |n: u32| rng.below(u64::from(n)) as u32;
+        let mut random = || {
+            let start = next(100);
+            range(start, start + next(20))
+        };
+
+        let assignments: Vec<_> = (0..500).map(|_| random()).collect();
+        let index = AssignmentIndex::new(&assignments);
+        for _ in 0..200 {
+            let query = random();
+            let mut overlapping = index.overlapping(query);
+            overlapping.sort_unstable();
+            let expected: Vec<_> = (0..assignments.len())
+                .filter(|&i| assignments[i].overlaps(&query))
+                .collect();
+            assert_eq!(overlapping, expected);
+
+            let mut containing = index.containing(query);
+            containing.sort_unstable();
+            let expected: Vec<_> = (0..assignments.len())
+                .filter(|&i| assignments[i].contains(&query))
+                .collect();
+            assert_eq!(containing, expected);
+        }
+
+        let pair = decode_line("5-9,1-2").unwrap();
+        let index = AssignmentIndex::from_pairs(&[pair]);
+        assert_eq!(index.overlapping(range(2, 5)), [1, 0]);
+    }
+}
@@ -0,0 +1,211 @@
+use aoc_common::{Answer, Example, Format, ParseError, Reporting, Result, Solver, Streaming};
+use coverage::Coverage;
+
+mod assignment;
+pub mod coverage;
+pub mod index;
+
+pub use assignment::Assignment;
+
+pub struct Day04;
+
+/// Assignments of two elves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pair {
+    pub first: Assignment,
+    pub second: Assignment,
+}
+
+impl Pair {
+    /// Whether one of the assignments contains the other.
+    pub fn fully_contained(&self) -> bool {
+        self.first.contains(&self.second) || self.second.contains(&self.first)
+    }
+
+    pub fn overlaps(&self) -> bool {
+        self.first.overlaps(&self.second)
+    }
+}
+
+/// Decodes a line such as `2-4,6-8`.
+pub fn decode_line(elem: &str) -> std::result::Result<Pair, ParseError> {
+    let elem = elem.trim_end();
+    let (first, second) = elem
+        .split_once(',')
+        .ok_or_else(|| ParseError::new(elem, elem, "expected two ranges separated by ','"))?;
+
+    Ok(Pair {
+        first: decode_range(elem, first)?,
+        second: decode_range(elem, second)?,
+    })
+}
+
+/// Decodes a line of any number of ranges, such as `2-4,6-8,3-5`.
+pub fn decode_assignments(line: &str) -> std::result::Result<Vec<Assignment>, ParseError> {
+    let line = line.trim_end();
+    line.split(',')
+        .map(|range| decode_range(line, range))
+        .collect()
+}
+
+/// Decodes a range of `line` such as `2-4`.
+fn decode_range(line: &str, range: &str) -> std::result::Result<Assignment, ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(line, range, "expected a range such as 2-4"))?;
+
+    let integer = |s: &str| {
+        s.parse::<u32>()
+            .map_err(|_| ParseError::new(line, s, "invalid integer"))
+    };
+    Assignment::new(integer(start)?, integer(end)?)
+        .ok_or_else(|| ParseError::new(line, range, "range ends before it starts"))
+}
+
+/// Running counts of a streamed list of pairs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub contained: usize,
+    pub overlapping: usize,
+}
+
+impl Streaming for Day04 {
+    type State = Counts;
+
+    fn feed(counts: &mut Counts, line: &str) -> std::result::Result<(), ParseError> {
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+
+        let pair = decode_line(line)?;
+        counts.contained += pair.fully_contained() as usize;
+        counts.overlapping += pair.overlaps() as usize;
+        Ok(())
+    }
+
+    fn part1(counts: &Counts) -> Result<Answer> {
+        Ok(counts.contained.into())
+    }
+
+    fn part2(counts: &Counts) -> Result<Answer> {
+        Ok(counts.overlapping.into())
+    }
+}
+
+/// How the sections are covered, each line being assignments of any number of elves.
+impl Reporting for Day04 {
+    fn report(input: &str, format: Format) -> Result<String> {
+        let lines = aoc_common::parse_lines(input, decode_assignments)?;
+        let coverage: Vec<_> = lines.iter().map(|a| Coverage::of(a)).collect();
+        Ok(coverage::format(&coverage, format))
+    }
+}
+
+impl Solver for Day04 {
+    const DAY: u8 = 4;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+",
+        part1: "2",
+        part2: "4",
+    };
+
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(aoc_common::parse_lines(input, decode_line)?)
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<Answer> {
+        let contained = pairs.iter().filter(|pair| pair.fully_contained()).count();
+        Ok(contained.into())
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<Answer> {
+        let overlapping = pairs.iter().filter(|pair| pair.overlaps()).count();
+        Ok(overlapping.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day04>();
+        aoc_common::check_stream_example::<Day04>();
+    }
+
+    #[test]
+    fn test_fully_contained() {
+        // Same range
+        assert!(decode_line("1-10,1-10").unwrap().fully_contained());
+        // First smaller
+        assert!(decode_line("10-101,0-101").unwrap().fully_contained());
+        assert!(decode_line("10-13,10-16").unwrap().fully_contained());
+        // Second smaller
+        assert!(decode_line("4-8,4-6").unwrap().fully_contained());
+        assert!(decode_line("30-190,31-190").unwrap().fully_contained());
+    }
+
+    #[test]
+    fn test_not_fully_contained() {
+        assert!(!decode_line("2-5,1-4").unwrap().fully_contained());
+        assert!(!decode_line("89-314,100-400").unwrap().fully_contained());
+    }
+
+    #[test]
+    fn test_overlap() {
+        // Same range
+        assert!(decode_line("1-10,1-10").unwrap().overlaps());
+        // First smaller, fully contained
+        assert!(decode_line("10-101,0-101").unwrap().overlaps());
+        assert!(decode_line("10-13,10-16").unwrap().overlaps());
+        // Second smaller, fully_contained
+        assert!(decode_line("4-8,4-6").unwrap().overlaps());
+        assert!(decode_line("30-190,31-190").unwrap().overlaps());
+        // Partial overlap
+        assert!(decode_line("2-5,1-4").unwrap().overlaps());
+        assert!(decode_line("89-314,100-400").unwrap().overlaps());
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let e = decode_line("1-10").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (1, "1-10"));
+
+        let e = decode_line("1-10,110").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (6, "110"));
+
+        let e = decode_line("1-10,1-x").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (8, "x"));
+
+        let e = decode_line("1-10,8-2").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (6, "8-2"));
+    }
+
+    #[test]
+    fn test_decode_assignments() {
+        let assignments = decode_assignments("2-4,6-8,3-5\r").unwrap();
+        assert_eq!(assignments.len(), 3);
+        assert_eq!(assignments[2], Assignment::new(3, 5).unwrap());
+        assert_eq!(decode_assignments("7-7").unwrap().len(), 1);
+
+        let e = decode_assignments("2-4,,3-5").unwrap_err();
+        assert_eq!(e.column, 5);
+    }
+
+    #[test]
+    fn no_overlap() {
+        assert!(!decode_line("12-12,13-14").unwrap().overlaps());
+        assert!(!decode_line("12-15,16-199").unwrap().overlaps());
+    }
+}
@@ -0,0 +1,308 @@
+use aoc_common::{Answer, Example, ParseError, Result, Solver};
+use std::collections::VecDeque;
+
+pub struct Day05;
+
+#[derive(Clone, Debug)]
+pub struct Stacks {
+    data: Vec<VecDeque<char>>,
+}
+
+/// Moves `count` crates from stack `src` to stack `dst`, 0-indexed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub src: usize,
+    pub dst: usize,
+}
+
+/// Parses a series of moves such as `move 1 from 2 to 1`, between `num_stacks` stacks.
+pub fn parse_moves(moves: &str, num_stacks: usize) -> std::result::Result<Vec<Move>, ParseError> {
+    aoc_common::parse_lines(moves, |line| parse_move(line, num_stacks))
+}
+
+/// Parses a move such as `move 1 from 2 to 1`.
+fn parse_move(line: &str, num_stacks: usize) -> std::result::Result<Move, ParseError> {
+    let words: Vec<_> = line.split_whitespace().collect();
+    let ["move", count, "from", src, "to", dst] = words[..] else {
+        return Err(ParseError::new(
+            line,
+            line.trim_end(),
+            "expected 'move <count> from <stack> to <stack>'",
+        ));
+    };
+
+    let integer = |s: &str| {
+        s.parse::<usize>()
+            .map_err(|_| ParseError::new(line, s, "invalid integer"))
+    };
+    // Make 0-indexed
+    let stack = |s: &str| match integer(s)? {
+        n @ 1.. if n <= num_stacks => Ok(n - 1),
+        _ => Err(ParseError::new(
+            line,
+            s,
+            format!("stack out of range 1..={num_stacks}"),
+        )),
+    };
+
+    Ok(Move {
+        count: integer(count)?,
+        src: stack(src)?,
+        dst: stack(dst)?,
+    })
+}
+
+impl Stacks {
+    /// Parses an initial state and returns the stacks for further transformation.
+    /// Front of stack is the bottom, the back is the top.
+    pub fn init(state: &str) -> std::result::Result<Stacks, ParseError> {
+        let first = state
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::eof(state, "expected an initial state"))?;
+        let num_stacks = first.chars().count();
+        if !(num_stacks + 1).is_multiple_of(4) {
+            return Err(ParseError::new(
+                state,
+                first,
+                "wrong text width in initial state",
+            ));
+        }
+        let num_stacks = (num_stacks + 1) / 4;
+
+        let mut data = Vec::new();
+        data.resize(num_stacks, VecDeque::new());
+
+        for line in state.lines() {
+            for (c, stack) in line.chars().skip(1).step_by(4).zip(data.iter_mut()) {
+                // Skip numbers, only keep letters
+                if let 'A'..='Z' = c {
+                    stack.push_front(c)
+                }
+            }
+        }
+
+        Ok(Stacks { data })
+    }
+
+    /// Parses a series of moves to apply to these stacks, checking that none takes more
+    /// crates than its stack holds.
+    pub fn parse_moves(&self, moves: &str) -> std::result::Result<Vec<Move>, ParseError> {
+        let mut heights: Vec<_> = self.data.iter().map(VecDeque::len).collect();
+        aoc_common::parse_lines(moves, |line| {
+            let m = parse_move(line, heights.len())?;
+            if heights[m.src] < m.count {
+                // The count is the second word
+                let count = line.split_whitespace().nth(1).unwrap_or(line);
+                return Err(ParseError::new(
+                    line,
+                    count,
+                    format!("stack {} only holds {} crate(s)", m.src + 1, heights[m.src]),
+                ));
+            }
+
+            heights[m.src] -= m.count;
+            heights[m.dst] += m.count;
+            Ok(m)
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Applies a series of moves to the stacks.
+    /// Panics if moves are invalid, which [`Stacks::parse_moves`] rules out.
+    /// move_at_once : whether all crates are moved simultaneously, or one at a time.
+    pub fn transform(&mut self, moves: &[Move], move_at_once: bool) -> &mut Self {
+        for &Move {
+            count: cnt,
+            src,
+            dst,
+        } in moves
+        {
+            let start_index = self.data[src].len() - cnt;
+
+            if move_at_once {
+                let pop_vals = self.data[src].drain(start_index..).collect::<Vec<_>>();
+                self.data[dst].extend(pop_vals);
+            } else {
+                let pop_vals = self.data[src]
+                    .drain(start_index..)
+                    .rev()
+                    .collect::<Vec<_>>();
+                self.data[dst].extend(pop_vals);
+            }
+        }
+
+        self
+    }
+
+    pub fn display(&self) -> String {
+        self.data.iter().fold(String::new(), |mut string, stack| {
+            string.push(*stack.back().unwrap_or(&' '));
+            string
+        })
+    }
+}
+
+/// Initial state of the stacks, and the moves to apply to it.
+#[derive(Clone, Debug)]
+pub struct Procedure {
+    pub stacks: Stacks,
+    pub moves: Vec<Move>,
+}
+
+impl Solver for Day05 {
+    const DAY: u8 = 5;
+
+    const EXAMPLE: Example = Example {
+        // No line continuation, which would eat the indentation of the first line
+        input: "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+",
+        part1: "CMZ",
+        part2: "MCD",
+    };
+
+    type Input = Procedure;
+
+    fn parse(input: &str) -> Result<Procedure> {
+        let mut blocks = aoc_common::blocks(input);
+        let (Some(initial), Some(moves)) = (blocks.next(), blocks.next()) else {
+            return Err(ParseError::eof(
+                input,
+                "expected initial state and moves separated by a blank line",
+            )
+            .into());
+        };
+
+        let stacks = Stacks::init(initial).map_err(|e| e.within(input, initial))?;
+        let moves = stacks
+            .parse_moves(moves)
+            .map_err(|e| e.within(input, moves))?;
+
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part1(procedure: &Procedure) -> Result<Answer> {
+        let mut stacks = procedure.stacks.clone();
+        Ok(stacks.transform(&procedure.moves, false).display().into())
+    }
+
+    fn part2(procedure: &Procedure) -> Result<Answer> {
+        let mut stacks = procedure.stacks.clone();
+        Ok(stacks.transform(&procedure.moves, true).display().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day05>();
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = Stacks::init("[A] [B] \n 1   2").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 1, "[A] [B] "));
+
+        let e = parse_moves("move 1 from 2 to 1\nmove 1 from 2 to 4", 3).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 18, "4"));
+
+        let e = parse_moves("move one from 2 to 1", 3).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 6, "one"));
+
+        let e = parse_moves("move 1 to 2", 3).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 1, "move 1 to 2"));
+
+        // Two crates are left on the first stack by then
+        let input = Day05::EXAMPLE
+            .input
+            .replace("move 1 from 1 to 2\n", "move 12 from 1 to 2\n");
+        let e = Day05::parse(&input).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Parse error: stack 1 only holds 2 crate(s) at line 9, column 6: \"12\""
+        );
+    }
+
+    #[test]
+    fn test_stacks() {
+        let mut stacks1 = Stacks::init(
+            "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 ",
+        )
+        .unwrap();
+
+        assert_eq!(
+            stacks1.data,
+            [
+                VecDeque::from(['Z', 'N']),
+                VecDeque::from(['M', 'C', 'D']),
+                VecDeque::from(['P'])
+            ]
+        );
+
+        let mut stacks2 = stacks1.clone();
+
+        stacks1.transform(
+            &parse_moves(
+                "move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2",
+                3,
+            )
+            .unwrap(),
+            false,
+        );
+
+        assert_eq!(
+            stacks1.data,
+            [
+                VecDeque::from(['C']),
+                VecDeque::from(['M']),
+                VecDeque::from(['P', 'D', 'N', 'Z'])
+            ]
+        );
+
+        stacks2.transform(
+            &parse_moves(
+                "move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2",
+                3,
+            )
+            .unwrap(),
+            true,
+        );
+
+        assert_eq!(
+            stacks2.data,
+            [
+                VecDeque::from(['M']),
+                VecDeque::from(['C']),
+                VecDeque::from(['P', 'Z', 'N', 'D'])
+            ]
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use aoc_common::{Answer, Error, Example, Result, Solver};
+use std::collections::HashSet;
+
+pub struct Day06;
+
+pub fn find_first_unique(content: &str, window_size: usize) -> Option<usize> {
+    Some(
+        content
+        .chars()
+        .collect::<Vec<_>>()
+        .windows(window_size)
+        .enumerate()
+        .find(|elem| {
+            let (_, elem) = elem;
+            let mut set = HashSet::new();
+            elem.iter().all(|x| set.insert(x))
+        })?
+        .0
+        // Add window size to index, such that the first window returns WINDOW_SIZE
+        + window_size,
+    )
+}
+
+impl Solver for Day06 {
+    const DAY: u8 = 6;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+mjqjpqmgbljsphdztnvjfqwrcgsmlb
+",
+        part1: "7",
+        part2: "19",
+    };
+
+    /// The datastream buffer.
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(input: &String) -> Result<Answer> {
+        let index = find_first_unique(input, 4).ok_or(Error::NoSolution)?;
+        Ok(index.into())
+    }
+
+    fn part2(input: &String) -> Result<Answer> {
+        let index = find_first_unique(input, 14).ok_or(Error::NoSolution)?;
+        Ok(index.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day06>();
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(find_first_unique("", 4), None);
+    }
+
+    #[test]
+    fn too_short() {
+        assert_eq!(find_first_unique("abc", 4), None);
+    }
+
+    #[test]
+    fn good() {
+        assert_eq!(find_first_unique("frtffuiunanimaldecompagnie", 6), Some(16));
+    }
+
+    #[test]
+    fn no_match() {
+        assert_eq!(find_first_unique("abcdcbabcdcba", 5), None);
+    }
+}
@@ -0,0 +1,161 @@
+use aoc_common::{Answer, Error, Example, ParseError, Result, Solver};
+use regex::Regex;
+use std::collections::HashMap;
+
+pub const THRESHOLD: u32 = 100_000;
+pub const MAX_SIZE: u32 = 40_000_000;
+
+pub struct Day07;
+
+/// Returns the size of each directory of the tree.
+pub fn parse_commands(commands: &str) -> std::result::Result<HashMap<String, u32>, ParseError> {
+    let cd_parent = Regex::new(r"^\$ cd \.\.").unwrap();
+    let cd_root = Regex::new(r"^\$ cd /").unwrap();
+    let cd_child = Regex::new(r"^\$ cd (?P<name>[[:alpha:]]+)$").unwrap();
+
+    // Good enough for our purposes
+    let file = Regex::new(r"^(?P<size>([1-9][0-9]*|[0-9])) (?P<name>[!-.0-~]*)$").unwrap();
+
+    let nop = Regex::new(r"^(\$ ls|dir [[:alpha:]]+)$").unwrap();
+
+    let mut map = HashMap::new();
+
+    // Structure: ./a/b/c/file.txt
+    let mut cwd = String::new();
+
+    // We assume we only visit each folder once, so we cut corners a bit.
+    for command in aoc_common::lines(commands) {
+        let command = command.trim_end();
+        if cd_parent.is_match(command) {
+            let i = cwd.rfind('/').ok_or_else(|| {
+                ParseError::new(commands, command, "can't go above the root directory")
+            })?;
+            cwd.truncate(i);
+        } else if cd_root.is_match(command) {
+            cwd = ".".to_string();
+        } else if let Some(child) = cd_child.captures(command) {
+            cwd.push('/');
+            cwd.push_str(&child["name"]);
+        } else if let Some(file) = file.captures(command) {
+            let size = file.name("size").unwrap().as_str();
+            let file_sz = size
+                .parse::<u32>()
+                .map_err(|_| ParseError::new(commands, size, "file too large"))?;
+            // The directory and its parents
+            let parents = cwd.match_indices('/').map(|(i, _)| &cwd[..i]);
+            for dir in parents.chain([cwd.as_str()]) {
+                let dir_sz = map.entry(dir.to_owned()).or_insert(0u32);
+                *dir_sz = dir_sz
+                    .checked_add(file_sz)
+                    .ok_or_else(|| ParseError::new(commands, size, "directory too large"))?;
+            }
+        } else if !nop.is_match(command) {
+            return Err(ParseError::new(commands, command, "invalid instruction"));
+        }
+    }
+
+    Ok(map)
+}
+
+impl Solver for Day07 {
+    const DAY: u8 = 7;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+",
+        part1: "95437",
+        part2: "24933642",
+    };
+
+    /// Size of each directory, by path.
+    type Input = HashMap<String, u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_commands(input)?)
+    }
+
+    fn part1(root: &Self::Input) -> Result<Answer> {
+        let total = root
+            .values()
+            .filter(|&&s| s <= THRESHOLD)
+            .map(|&s| u64::from(s))
+            .sum::<u64>();
+        Ok(total.into())
+    }
+
+    fn part2(root: &Self::Input) -> Result<Answer> {
+        let space_to_free = root
+            .get(".")
+            .ok_or(Error::NoSolution)?
+            .saturating_sub(MAX_SIZE);
+
+        let smallest = root
+            .values()
+            .filter(|&&s| s > space_to_free)
+            .min()
+            .ok_or(Error::NoSolution)?;
+        Ok((*smallest).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day07>();
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        let e = parse_commands("$ cd /\n$ ls\n$ rm -rf a").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 1, "$ rm -rf a"));
+
+        let e = parse_commands("$ cd ..").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+
+        let e = parse_commands("$ cd /\n4000000000 a\n$ cd b\n400000000 c").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.message.as_str()),
+            (4, 1, "directory too large")
+        );
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(
+            parse_commands(Day07::EXAMPLE.input).unwrap(),
+            [
+                (".".to_string(), 48381165),
+                ("./a".to_string(), 94853),
+                ("./a/e".to_string(), 584),
+                ("./d".to_string(), 24933642),
+            ]
+            .into()
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use aoc_common::{Answer, Example, Grid, ParseError, Result, Solver, Vec2};
+use std::cmp::Ordering;
+
+pub struct Day08;
+
+pub fn parse_map(text: &str) -> std::result::Result<Grid<u32>, ParseError> {
+    Grid::parse(text, "expected a digit", |_, c| c.to_digit(10))
+}
+
+pub fn count_visible(tiles: &Grid<u32>) -> usize {
+    tiles
+        .iter()
+        .filter(|&(p, &elem)| {
+            // Search in every direction, up to the edge
+            Vec2::ORTHOGONAL
+                .into_iter()
+                .any(|d| tiles.ray(p, d).all(|(_, &x)| x < elem))
+        })
+        .count()
+}
+
+pub fn max_scenic_score(tiles: &Grid<u32>) -> usize {
+    tiles
+        .iter()
+        .map(|(p, &elem)| {
+            Vec2::ORTHOGONAL
+                .into_iter()
+                .map(|d| {
+                    let mut cnt = 0;
+
+                    for (_, &e) in tiles.ray(p, d) {
+                        match e.cmp(&elem) {
+                            Ordering::Less => cnt += 1,
+                            // If same height or taller
+                            _ => {
+                                cnt += 1;
+                                break;
+                            }
+                        }
+                    }
+
+                    cnt
+                })
+                .product()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+impl Solver for Day08 {
+    const DAY: u8 = 8;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+30373
+25512
+65332
+33549
+35390
+",
+        part1: "21",
+        part2: "8",
+    };
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_map(input)?)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer> {
+        Ok(count_visible(map).into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer> {
+        Ok(max_scenic_score(map).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day08>();
+    }
+
+    #[test]
+    fn test_parse_map() {
+        assert_eq!(
+            parse_map(
+                "\
+133456
+934567
+345678"
+            )
+            .unwrap(),
+            Grid::from_rows(vec![
+                vec![1, 3, 3, 4, 5, 6],
+                vec![9, 3, 4, 5, 6, 7],
+                vec![3, 4, 5, 6, 7, 8]
+            ])
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_map_errors() {
+        let e = parse_map("123\n4x6").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "x"));
+
+        let e = parse_map("123\n45").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "45"));
+    }
+
+    #[test]
+    fn test_count_visible() {
+        assert_eq!(
+            count_visible(
+                &Grid::from_rows(vec![
+                    vec![1, 3, 3, 4, 5, 6],
+                    vec![9, 3, 4, 4, 6, 7],
+                    vec![3, 4, 5, 9, 1, 8],
+                    vec![2, 4, 9, 7, 5, 1],
+                ])
+                .unwrap()
+            ),
+            21
+        );
+    }
+
+    #[test]
+    fn test_max_scenic_score() {
+        assert_eq!(
+            max_scenic_score(
+                &Grid::from_rows(vec![
+                    vec![3, 0, 3, 7, 3],
+                    vec![2, 5, 5, 1, 2],
+                    vec![6, 5, 3, 3, 2],
+                    vec![3, 3, 5, 4, 9],
+                    vec![3, 5, 3, 9, 0],
+                ])
+                .unwrap()
+            ),
+            8
+        );
+    }
+}
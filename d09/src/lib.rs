@@ -0,0 +1,274 @@
+use aoc_common::{Answer, Example, ParseError, Point, Result, Solver, Streaming, Vec2};
+use std::collections::HashSet;
+
+use Move::*;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Move {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Move {
+    pub fn to_tile_movement(self) -> Vec2 {
+        match self {
+            Up => Vec2::UP,
+            Down => Vec2::DOWN,
+            Left => Vec2::LEFT,
+            Right => Vec2::RIGHT,
+        }
+    }
+}
+
+/// Moves `knot` towards `lead` if they aren't touching anymore.
+pub fn follow(knot: &mut Point, lead: Point) {
+    let d = lead - *knot;
+
+    if d.chebyshev() > 1 {
+        *knot += d.signum();
+    }
+}
+
+/// Parses each line into a direction and a number of steps.
+pub fn parse_moves(content: &str) -> std::result::Result<Vec<(Move, usize)>, ParseError> {
+    aoc_common::parse_lines(content, parse_line)
+}
+
+/// Parses a line such as `R 4`: direction, space, count.
+fn parse_line(l: &str) -> std::result::Result<(Move, usize), ParseError> {
+    let l = l.trim_end();
+    let Some((d, cnt)) = l.split_once(' ') else {
+        return Err(ParseError::new(l, l, "expected a direction and a count"));
+    };
+
+    let d = match d {
+        "U" => Up,
+        "D" => Down,
+        "L" => Left,
+        "R" => Right,
+        _ => return Err(ParseError::new(l, d, "expected 'U', 'D', 'L' or 'R'")),
+    };
+
+    let cnt = cnt
+        .parse::<usize>()
+        .map_err(|_| ParseError::new(l, cnt, "invalid integer"))?;
+
+    Ok((d, cnt))
+}
+
+/// A rope of knots starting at the origin, and the tiles its tail visited.
+#[derive(Clone, Debug)]
+pub struct Rope {
+    knots: Vec<Point>,
+    tail_visited: HashSet<Point>,
+}
+
+impl Rope {
+    /// Panics if `n` is 0.
+    pub fn new(n: usize) -> Self {
+        let knots = vec![Point::ZERO; n];
+        let tail_visited = HashSet::from([*knots.last().expect("A rope needs a knot")]);
+        Rope {
+            knots,
+            tail_visited,
+        }
+    }
+
+    /// Moves the head by one tile, the other knots following.
+    pub fn step(&mut self, mv: Move) {
+        self.knots[0] += mv.to_tile_movement();
+        for i in 1..self.knots.len() {
+            let prev = self.knots[i - 1];
+            follow(&mut self.knots[i], prev);
+        }
+        self.tail_visited.insert(*self.knots.last().unwrap());
+    }
+
+    /// Number of distinct tiles the tail visited, starting tile included.
+    pub fn visited(&self) -> usize {
+        self.tail_visited.len()
+    }
+}
+
+pub fn visited_tiles(moves: &[(Move, usize)], n: usize) -> usize {
+    let mut rope = Rope::new(n);
+    for &(mv, cnt) in moves {
+        for _ in 0..cnt {
+            rope.step(mv);
+        }
+    }
+    rope.visited()
+}
+
+/// Ropes of both parts, moved as lines of a stream come in.
+#[derive(Clone, Debug)]
+pub struct Ropes {
+    pub short: Rope,
+    pub long: Rope,
+}
+
+impl Default for Ropes {
+    fn default() -> Self {
+        Ropes {
+            short: Rope::new(2),
+            long: Rope::new(10),
+        }
+    }
+}
+
+impl Streaming for Day09 {
+    type State = Ropes;
+
+    fn feed(ropes: &mut Ropes, line: &str) -> std::result::Result<(), ParseError> {
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+
+        let (mv, cnt) = parse_line(line)?;
+        for _ in 0..cnt {
+            ropes.short.step(mv);
+            ropes.long.step(mv);
+        }
+        Ok(())
+    }
+
+    fn part1(ropes: &Ropes) -> Result<Answer> {
+        Ok(ropes.short.visited().into())
+    }
+
+    fn part2(ropes: &Ropes) -> Result<Answer> {
+        Ok(ropes.long.visited().into())
+    }
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: u8 = 9;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+",
+        part1: "13",
+        part2: "1",
+    };
+
+    /// Directions and their number of steps.
+    type Input = Vec<(Move, usize)>;
+
+    fn parse(input: &str) -> Result<Vec<(Move, usize)>> {
+        Ok(parse_moves(input)?)
+    }
+
+    fn part1(moves: &Vec<(Move, usize)>) -> Result<Answer> {
+        Ok(visited_tiles(moves, 2).into())
+    }
+
+    fn part2(moves: &Vec<(Move, usize)>) -> Result<Answer> {
+        Ok(visited_tiles(moves, 10).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day09>();
+        aoc_common::check_stream_example::<Day09>();
+    }
+
+    #[test]
+    fn test_parse_moves() {
+        assert_eq!(
+            parse_moves(
+                "\
+U 1
+D 1
+L 3
+R 2
+U 10"
+            )
+            .unwrap(),
+            [(Up, 1), (Down, 1), (Left, 3), (Right, 2), (Up, 10)]
+        );
+
+        // Counts aren't expanded
+        let moves = parse_moves("R 99999999999\n").unwrap();
+        assert_eq!(moves, [(Right, 99_999_999_999)]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = parse_moves("U 1\nX 4").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "X"));
+
+        let e = parse_moves("U 1\nD -4").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "-4"));
+
+        let e = parse_moves("U1").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (1, 1, "U1"));
+    }
+
+    #[test]
+    fn test_visited_tiles_part2_b() {
+        let moves_raw = "\
+R 15
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+        let moves = parse_moves(moves_raw).unwrap();
+        assert_eq!(visited_tiles(&moves, 10), calculate(moves_raw, 10));
+    }
+
+    // Other implementation, for reference. Adapted from https://github.com/dellink/advent-of-code/blob/main/2022/src/bin/09.rs
+    fn calculate(input: &str, length: usize) -> usize {
+        let mut rope = vec![(0i32, 0i32); length];
+        let mut visited = HashSet::new();
+        visited.insert((0, 0));
+
+        for line in input.lines() {
+            let instruction = line.split_once(' ').unwrap();
+
+            let (dx, dy) = match instruction.0 {
+                "U" => (0, 1),
+                "D" => (0, -1),
+                "R" => (1, 0),
+                "L" => (-1, 0),
+                _ => unreachable!(),
+            };
+
+            let steps = instruction.1.parse::<usize>().unwrap();
+
+            for _ in 0..steps {
+                rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+                for i in 1..rope.len() {
+                    let (dx, dy) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+                    if dx.abs() > 1 || dy.abs() > 1 {
+                        rope[i].0 += dx.signum();
+                        rope[i].1 += dy.signum();
+                    }
+                }
+                visited.insert(rope[length - 1]);
+            }
+        }
+
+        visited.len()
+    }
+}
@@ -0,0 +1,425 @@
+use aoc_common::{Answer, Example, ParseError, Result, Solver, Streaming};
+
+pub struct Day10;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Op {
+    Noop,
+    Addx(i32),
+}
+
+#[derive(Debug)]
+pub struct Ops {
+    pub ops: Vec<Op>,
+}
+
+impl Op {
+    /// Parses a line such as `addx -4`.
+    pub fn parse(ln: &str) -> std::result::Result<Self, ParseError> {
+        let ln = ln.trim_end();
+        match ln {
+            "noop" => Ok(Op::Noop),
+
+            // Ignore "addx " characters
+            _ if ln.starts_with("addx ") => {
+                let val = &ln[5..];
+                val.parse::<i32>()
+                    .map(Op::Addx)
+                    .map_err(|_| ParseError::new(ln, val, "invalid integer"))
+            }
+
+            _ => Err(ParseError::new(ln, ln, "expected 'noop' or 'addx <value>'")),
+        }
+    }
+}
+
+impl Ops {
+    /// Also runs the program, rejecting instructions which overflow the device.
+    pub fn parse(content: &str) -> std::result::Result<Self, ParseError> {
+        let mut cpu = Cpu::default();
+        let ops = aoc_common::parse_lines(content, |line| cpu.step(line))?;
+        Ok(Ops { ops })
+    }
+
+    fn run(&self) -> Cpu {
+        let mut cpu = Cpu::default();
+        for op in &self.ops {
+            cpu.execute(op).expect("Programs are run when parsed");
+        }
+        cpu
+    }
+
+    pub fn total_signal_strengths(&self) -> i64 {
+        self.run().signal_strengths
+    }
+
+    pub fn render(&self) -> String {
+        self.run().screen
+    }
+}
+
+/// State of the device, updated one instruction at a time.
+#[derive(Clone, Debug)]
+pub struct Cpu {
+    /// Program counter
+    pc: u64,
+    /// Register
+    x: i64,
+    /// Sum over the cycles of interest so far
+    signal_strengths: i64,
+    /// Rows drawn so far, each preceded by a newline
+    screen: String,
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Cpu {
+            pc: 0,
+            x: 1,
+            signal_strengths: 0,
+            screen: String::new(),
+        }
+    }
+}
+
+impl Cpu {
+    /// Fails if the register or the signal strengths overflow.
+    pub fn execute(&mut self, op: &Op) -> std::result::Result<(), &'static str> {
+        let cnt = match op {
+            Op::Noop => 1,
+            Op::Addx(_) => 2,
+        };
+
+        for _ in 0..cnt {
+            // Draw the first 240 cycles only
+            if self.pc < 240 {
+                // Format line
+                if self.pc.is_multiple_of(40) {
+                    self.screen.push('\n');
+                }
+
+                // Start row at pixel 0
+                let px = (self.pc % 40) as i64;
+                match self.x == px - 1 || self.x == px || self.x == px + 1 {
+                    true => self.screen.push('█'),
+                    false => self.screen.push('.'),
+                }
+            }
+
+            self.pc += 1;
+            if let 20 | 60 | 100 | 140 | 180 | 220 = self.pc {
+                self.signal_strengths = self
+                    .x
+                    .checked_mul(self.pc as i64)
+                    .and_then(|strength| self.signal_strengths.checked_add(strength))
+                    .ok_or("signal strengths too large")?;
+            }
+        }
+
+        if let Op::Addx(val) = op {
+            self.x = self
+                .x
+                .checked_add(i64::from(*val))
+                .ok_or("register out of range")?;
+        }
+        Ok(())
+    }
+
+    /// Parses and executes an instruction, errors being located within `line`.
+    fn step(&mut self, line: &str) -> std::result::Result<Op, ParseError> {
+        let op = Op::parse(line)?;
+        self.execute(&op)
+            .map_err(|e| ParseError::new(line, line.trim_end(), e))?;
+        Ok(op)
+    }
+
+    pub fn signal_strengths(&self) -> i64 {
+        self.signal_strengths
+    }
+
+    pub fn screen(&self) -> &str {
+        &self.screen
+    }
+}
+
+impl Streaming for Day10 {
+    type State = Cpu;
+
+    fn feed(cpu: &mut Cpu, line: &str) -> std::result::Result<(), ParseError> {
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+
+        cpu.step(line)?;
+        Ok(())
+    }
+
+    fn part1(cpu: &Cpu) -> Result<Answer> {
+        Ok(cpu.signal_strengths().into())
+    }
+
+    fn part2(cpu: &Cpu) -> Result<Answer> {
+        Ok(Answer::grid(cpu.screen()))
+    }
+}
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+",
+        part1: "13140",
+        part2: "\
+██..██..██..██..██..██..██..██..██..██..
+███...███...███...███...███...███...███.
+████....████....████....████....████....
+█████.....█████.....█████.....█████.....
+██████......██████......██████......████
+███████.......███████.......███████.....",
+    };
+
+    type Input = Ops;
+
+    fn parse(input: &str) -> Result<Ops> {
+        Ok(Ops::parse(input)?)
+    }
+
+    fn part1(ops: &Ops) -> Result<Answer> {
+        Ok(ops.total_signal_strengths().into())
+    }
+
+    fn part2(ops: &Ops) -> Result<Answer> {
+        Ok(Answer::grid(&ops.render()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day10>();
+        aoc_common::check_stream_example::<Day10>();
+    }
+
+    #[test]
+    fn test_parse_ops() {
+        assert_eq!(
+            Ops::parse(
+                "\
+noop
+noop
+addx 3
+addx 304
+addx -4
+noop
+addx 0
+addx 13"
+            )
+            .unwrap()
+            .ops,
+            vec![
+                Op::Noop,
+                Op::Noop,
+                Op::Addx(3),
+                Op::Addx(304),
+                Op::Addx(-4),
+                Op::Noop,
+                Op::Addx(0),
+                Op::Addx(13)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = Ops::parse("noop\naddx 1x").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 6, "1x"));
+
+        let e = Ops::parse("noop\nnop").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "nop"));
+    }
+
+    #[test]
+    fn test_overflow() {
+        // Strengths of 2 * 10^9 * 20 and more fit
+        let ops = Ops::parse("addx 2000000000\nnoop\n").unwrap();
+        let mut cpu = Cpu::default();
+        for op in &ops.ops {
+            cpu.execute(op).unwrap();
+        }
+        assert_eq!(cpu.x, 2_000_000_001);
+
+        let mut cpu = Cpu {
+            x: i64::MAX - 1,
+            ..Cpu::default()
+        };
+        assert_eq!(cpu.execute(&Op::Addx(2)), Err("register out of range"));
+
+        let mut cpu = Cpu {
+            pc: 19,
+            x: i64::MAX / 10,
+            ..Cpu::default()
+        };
+        assert_eq!(cpu.execute(&Op::Noop), Err("signal strengths too large"));
+
+        let start = Cpu {
+            x: i64::MAX - 1,
+            ..Cpu::default()
+        };
+        let e =
+            aoc_common::feed_lines_into::<Day10>("noop\naddx 2\n".as_bytes(), start).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Parse error: register out of range at line 2, column 1: \"addx 2\""
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use aoc_common::{Answer, Example, ParseError, Solver};
+use std::{collections::VecDeque, str::FromStr};
+
+pub struct Day11;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Monkey {
+    pub items: VecDeque<u64>,
+    pub operation: Operation,
+    pub div_cmp: u64,
+    pub if_divisible: usize,
+    pub else_divisible: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MonkeyGroup {
+    pub monkeys: Vec<Monkey>,
+}
+
+impl MonkeyGroup {
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        const ITEMS_OFFSET: usize = "  Starting items: ".len();
+        const OPERATION_OFFSET: usize = "  Operation: new = old ".len();
+        const DIV_CMP_OFFSET: usize = "  Test: divisible by ".len();
+        const IF_DIVISIBLE_OFFSET: usize = "    If true: throw to monkey ".len();
+        const ELSE_DIVISIBLE_OFFSET: usize = "    If false: throw to monkey ".len();
+
+        fn integer<T: FromStr>(content: &str, s: &str) -> Result<T, ParseError> {
+            s.parse()
+                .map_err(|_| ParseError::new(content, s, "invalid integer"))
+        }
+
+        // Monkeys thrown to, checked once we know how many there are
+        let mut targets = Vec::new();
+
+        let monkeys = aoc_common::blocks(content)
+            .map(|par| {
+                let mut lines = par.lines();
+
+                // Returns the line's text after `offset`
+                let mut next_field = |offset: usize| {
+                    let line = lines.next().ok_or_else(|| {
+                        ParseError::new(content, &par[par.len()..], "missing line")
+                    })?;
+                    let line = line.trim_end();
+                    line.get(offset..)
+                        .filter(|field| !field.is_empty())
+                        .ok_or_else(|| ParseError::new(content, line, "line too short"))
+                };
+
+                // Header
+                next_field(0)?;
+
+                let items = next_field(ITEMS_OFFSET)?
+                    .split(", ")
+                    .map(|elem| integer(content, elem))
+                    .collect::<Result<VecDeque<_>, _>>()?;
+
+                let operation = next_field(OPERATION_OFFSET)?;
+                let operation = match operation.split_once(' ') {
+                    Some(("*", "old")) => Operation::Square,
+                    Some(("+", x)) => Operation::Add(integer(content, x)?),
+                    Some(("*", x)) => Operation::Multiply(integer(content, x)?),
+                    _ => {
+                        return Err(ParseError::new(
+                            content,
+                            operation,
+                            "expected '+ <n>', '* <n>' or '* old'",
+                        ))
+                    }
+                };
+
+                let div_cmp = next_field(DIV_CMP_OFFSET)?;
+                let div_cmp = match integer(content, div_cmp)? {
+                    0 => return Err(ParseError::new(content, div_cmp, "can't divide by 0")),
+                    n => n,
+                };
+
+                let if_divisible = next_field(IF_DIVISIBLE_OFFSET)?;
+                let else_divisible = next_field(ELSE_DIVISIBLE_OFFSET)?;
+                targets.extend([if_divisible, else_divisible]);
+
+                Ok(Monkey {
+                    items,
+                    operation,
+                    div_cmp,
+                    if_divisible: integer(content, if_divisible)?,
+                    else_divisible: integer(content, else_divisible)?,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if monkeys.len() < 2 {
+            return Err(ParseError::eof(content, "too few monkeys (>= 2 needed)"));
+        }
+
+        for target in targets {
+            if integer::<usize>(content, target)? >= monkeys.len() {
+                return Err(ParseError::new(content, target, "no such monkey"));
+            }
+        }
+
+        Ok(MonkeyGroup { monkeys })
+    }
+
+    pub fn score(mut self, num_rounds: usize, worry_reduction: impl Fn(u64) -> u64) -> usize {
+        let num_monkeys = self.monkeys.len();
+        assert!(num_monkeys >= 2, "Too few monkeys (>= 2 needed)");
+
+        let mut inspections = vec![0; num_monkeys];
+
+        for _ in 0..num_rounds {
+            for (i, inspections) in inspections.iter_mut().enumerate() {
+                // Clone monkey, then delete original items
+                let mut monkey = self.monkeys[i].clone();
+                self.monkeys[i].items.clear();
+
+                *inspections += monkey.items.len();
+                while let Some(item) = monkey.items.pop_front() {
+                    let item = worry_reduction(match monkey.operation {
+                        Operation::Add(x) => item + x,
+                        Operation::Multiply(x) => item * x,
+                        Operation::Square => item * item,
+                    });
+
+                    let throw_to = match item.is_multiple_of(monkey.div_cmp) {
+                        true => monkey.if_divisible,
+                        false => monkey.else_divisible,
+                    };
+
+                    self.monkeys[throw_to].items.push_back(item);
+                }
+            }
+        }
+
+        // Sort by decreasing order
+        inspections.sort_unstable_by(|a, b| a.cmp(b).reverse());
+        inspections[0] * inspections[1]
+    }
+}
+
+impl Solver for Day11 {
+    const DAY: u8 = 11;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+",
+        part1: "10605",
+        part2: "2713310158",
+    };
+
+    type Input = MonkeyGroup;
+
+    fn parse(input: &str) -> aoc_common::Result<MonkeyGroup> {
+        Ok(MonkeyGroup::parse(input)?)
+    }
+
+    fn part1(group: &MonkeyGroup) -> aoc_common::Result<Answer> {
+        Ok(group.clone().score(20, |x| x / 3).into())
+    }
+
+    fn part2(group: &MonkeyGroup) -> aoc_common::Result<Answer> {
+        // We use the product of all the moduli to avoid overflow when computing new worry.
+        let modulus: u64 = group.monkeys.iter().map(|m| m.div_cmp).product();
+        Ok(group.clone().score(10000, |x| x % modulus).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day11>();
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            MonkeyGroup::parse(TEXT).unwrap().monkeys,
+            vec![
+                Monkey {
+                    items: vec![79, 98].into(),
+                    operation: Operation::Multiply(19),
+                    div_cmp: 23,
+                    if_divisible: 2,
+                    else_divisible: 3
+                },
+                Monkey {
+                    items: vec![54, 65, 75, 74].into(),
+                    operation: Operation::Add(6),
+                    div_cmp: 19,
+                    if_divisible: 2,
+                    else_divisible: 0
+                },
+                Monkey {
+                    items: vec![79, 60, 97].into(),
+                    operation: Operation::Square,
+                    div_cmp: 13,
+                    if_divisible: 1,
+                    else_divisible: 3
+                },
+                Monkey {
+                    items: vec![74].into(),
+                    operation: Operation::Add(3),
+                    div_cmp: 17,
+                    if_divisible: 0,
+                    else_divisible: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let text = TEXT.replace("new = old * 19", "new = old - 19");
+        let e = MonkeyGroup::parse(&text).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (3, 24, "- 19"));
+
+        let text = TEXT.replace("throw to monkey 3", "throw to monkey 4");
+        let e = MonkeyGroup::parse(&text).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (6, 31, "4"));
+
+        let text = TEXT.replace("79, 98", "79, x");
+        let e = MonkeyGroup::parse(&text).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 23, "x"));
+
+        let e = MonkeyGroup::parse(&TEXT[..TEXT.find("    If false").unwrap()]).unwrap_err();
+        assert_eq!(e.line, 5);
+    }
+
+    const TEXT: &str = Day11::EXAMPLE.input;
+}
@@ -0,0 +1,144 @@
+use aoc_common::{search, Answer, Error, Example, Grid, ParseError, Point, Result, Solver};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Map {
+    pub heights: Grid<u8>,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Map {
+    pub fn parse(content: &str) -> std::result::Result<Self, ParseError> {
+        let mut start: Option<_> = None;
+        let mut end: Option<_> = None;
+
+        let heights = Grid::parse(content, "character out of range: [a-zSE]", |p, c| {
+            let c = match c {
+                'S' => {
+                    start = Some(p);
+                    'a'
+                }
+                'E' => {
+                    end = Some(p);
+                    'z'
+                }
+                _ => c,
+            };
+
+            c.is_ascii_lowercase().then(|| c as u8 - b'a')
+        })?;
+
+        Ok(Self {
+            heights,
+            start: start.ok_or_else(|| ParseError::eof(content, "no start point found"))?,
+            end: end.ok_or_else(|| ParseError::eof(content, "no endpoint found"))?,
+        })
+    }
+
+    pub fn solve(&self) -> Option<u32> {
+        self.solve_with_start(self.start)
+    }
+
+    pub fn solve_with_start(&self, start_point: Point) -> Option<u32> {
+        self.shortest_from([start_point])
+    }
+
+    /// Fewest steps from the lowest tile which is closest to the end.
+    pub fn find_best(&self) -> Option<u32> {
+        self.shortest_from(
+            self.heights
+                .iter()
+                .filter(|&(_, &h)| h == 0)
+                .map(|(p, _)| p),
+        )
+    }
+
+    fn shortest_from(&self, starts: impl IntoIterator<Item = Point>) -> Option<u32> {
+        let path = search::bfs(
+            starts,
+            |&tile| {
+                self.heights
+                    .neighbors4(tile)
+                    .filter(move |&next| self.heights[next] <= self.heights[tile] + 1)
+            },
+            |&tile| tile == self.end,
+        )?;
+
+        Some(path.cost as u32)
+    }
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u8 = 12;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+",
+        part1: "31",
+        part2: "29",
+    };
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Map> {
+        Ok(Map::parse(input)?)
+    }
+
+    fn part1(map: &Map) -> Result<Answer> {
+        Ok(map.solve().ok_or(Error::NoSolution)?.into())
+    }
+
+    fn part2(map: &Map) -> Result<Answer> {
+        Ok(map.find_best().ok_or(Error::NoSolution)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day12>();
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Map::parse(Day12::EXAMPLE.input).unwrap(),
+            Map {
+                // For padding
+                #[allow(clippy::zero_prefixed_literal)]
+                heights: Grid::from_rows(vec![
+                    vec![0, 0, 1, 16, 15, 14, 13, 12],
+                    vec![0, 1, 2, 17, 24, 23, 23, 11],
+                    vec![0, 2, 2, 18, 25, 25, 23, 10],
+                    vec![0, 2, 2, 19, 20, 21, 22, 09],
+                    vec![0, 1, 3, 04, 05, 06, 07, 08]
+                ])
+                .unwrap(),
+                start: Point::new(0, 0),
+                end: Point::new(5, 2)
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = Map::parse("Sab\nc-E").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "-"));
+
+        let e = Map::parse("Sab\ncE").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 1, "cE"));
+
+        let e = Map::parse("Sab\ncde").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 4, ""));
+    }
+}
@@ -0,0 +1,297 @@
+use aoc_common::{Answer, Example, ParseError, Result, Solver};
+use core::cmp::Ordering;
+use itertools::Itertools;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Elem {
+    List(Vec<Elem>),
+    Num(u32),
+}
+
+impl Ord for Elem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Elem::Num(a), Elem::Num(b)) => a.cmp(b),
+            (Elem::List(a), Elem::List(b)) => {
+                let mut ait = a.iter();
+                let mut bit = b.iter();
+                'out: loop {
+                    let a = ait.next();
+                    let b = bit.next();
+                    match (a, b) {
+                        (None, Some(_)) => break 'out Ordering::Less,
+                        (Some(_), None) => break 'out Ordering::Greater,
+                        (None, None) => break 'out Ordering::Equal,
+
+                        (Some(aval), Some(bval)) => match aval.cmp(bval) {
+                            // continue searching
+                            Ordering::Equal => (),
+                            // done
+                            o => break 'out o,
+                        },
+                    }
+                }
+            }
+            (Elem::Num(_), Elem::List(_)) => Elem::List(vec![self.clone()]).cmp(other),
+            (Elem::List(_), Elem::Num(_)) => self.cmp(&Elem::List(vec![other.clone()])),
+        }
+    }
+}
+
+impl PartialOrd for Elem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Recursive descent over a packet, from `pos`.
+struct Parser<'a> {
+    packet: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.packet[self.pos..].chars().next()
+    }
+
+    /// Points at the character at `pos`, or at the end of the packet.
+    fn unexpected(&self, msg: &str) -> ParseError {
+        match self.peek() {
+            Some(c) => ParseError::new(
+                self.packet,
+                &self.packet[self.pos..self.pos + c.len_utf8()],
+                msg,
+            ),
+            None => ParseError::eof(self.packet, msg),
+        }
+    }
+
+    fn list(&mut self) -> std::result::Result<Elem, ParseError> {
+        if self.peek() != Some('[') {
+            return Err(self.unexpected("expected '['"));
+        }
+        self.pos += 1;
+
+        let mut elems = vec![];
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(Elem::List(elems));
+        }
+        loop {
+            elems.push(self.elem()?);
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {
+                    self.pos += 1;
+                    return Ok(Elem::List(elems));
+                }
+                Some(_) => return Err(self.unexpected("expected ',' or ']'")),
+                None => return Err(self.unexpected("unclosed brackets")),
+            }
+        }
+    }
+
+    fn elem(&mut self) -> std::result::Result<Elem, ParseError> {
+        match self.peek() {
+            Some('[') => self.list(),
+            Some(c) if c.is_ascii_digit() => {
+                let rest = &self.packet[self.pos..];
+                let digits = &rest[..rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len())];
+                self.pos += digits.len();
+                digits
+                    .parse()
+                    .map(Elem::Num)
+                    .map_err(|_| ParseError::new(self.packet, digits, "integer too large"))
+            }
+            _ => Err(self.unexpected("expected '[' or an integer")),
+        }
+    }
+}
+
+pub fn parse_packet<T: AsRef<str> + ?Sized>(packet: &T) -> std::result::Result<Elem, ParseError> {
+    let packet = packet.as_ref().trim_end();
+    if packet.is_empty() {
+        return Err(ParseError::eof(packet, "expected a packet"));
+    }
+
+    let mut parser = Parser { packet, pos: 0 };
+    let elem = parser.list()?;
+    if parser.pos < packet.len() {
+        return Err(parser.unexpected("unexpected text after the packet"));
+    }
+    Ok(elem)
+}
+
+/// Parses every non-empty line as a packet.
+pub fn parse_packets(content: &str) -> std::result::Result<Vec<Elem>, ParseError> {
+    aoc_common::parse_lines(content, parse_packet)
+}
+
+pub fn run_1(packets: &[Elem]) -> usize {
+    packets
+        .iter()
+        .tuples()
+        .enumerate()
+        .filter_map(|(i, (a, b))| if a < b { Some(i + 1) } else { None })
+        .sum()
+}
+
+pub fn run_2(packets: &[Elem]) -> usize {
+    let mut all_packets: Vec<_> = packets
+        .iter()
+        .cloned()
+        .chain([
+            Elem::List(vec![Elem::List(vec![Elem::Num(2)])]),
+            Elem::List(vec![Elem::List(vec![Elem::Num(6)])]),
+        ])
+        .collect();
+
+    all_packets.sort();
+    (all_packets
+        .iter()
+        .position(|e| *e == Elem::List(vec![Elem::List(vec![Elem::Num(2)])]))
+        .unwrap()
+        + 1)
+        * (all_packets
+            .iter()
+            .position(|e| *e == Elem::List(vec![Elem::List(vec![Elem::Num(6)])]))
+            .unwrap()
+            + 1)
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: u8 = 13;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+",
+        part1: "13",
+        part2: "140",
+    };
+
+    type Input = Vec<Elem>;
+
+    fn parse(input: &str) -> Result<Vec<Elem>> {
+        Ok(parse_packets(input)?)
+    }
+
+    fn part1(packets: &Vec<Elem>) -> Result<Answer> {
+        Ok(run_1(packets).into())
+    }
+
+    fn part2(packets: &Vec<Elem>) -> Result<Answer> {
+        Ok(run_2(packets).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day13>();
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_packet("[1,[5,6],[[]],[[3]]]").unwrap(),
+            Elem::List(vec![
+                Elem::Num(1),
+                Elem::List(vec![Elem::Num(5), Elem::Num(6)]),
+                Elem::List(vec![Elem::List(vec![])]),
+                Elem::List(vec![Elem::List(vec![Elem::Num(3)])])
+            ])
+        );
+    }
+    #[test]
+    fn test_parse_errors() {
+        let e = parse_packet("[1,[2,3]").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (9, ""));
+
+        let e = parse_packet("[1,99999999999]").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (4, "99999999999"));
+
+        let e = parse_packet("1,[2]").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (1, "1"));
+
+        let e = parse_packets("[1]\n\n[2]\n[[3]").unwrap_err();
+        assert_eq!((e.line, e.column), (4, 5));
+
+        let e = parse_packet("[1,x,2]").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (4, "x"));
+
+        let e = parse_packet("[1]]]").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (4, "]"));
+
+        let e = parse_packet("[1 2]").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (3, " "));
+
+        let e = parse_packet("[1,]").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (4, "]"));
+    }
+    #[test]
+    fn test_order1() {
+        assert!(parse_packet("[1,1,3,1,1]").unwrap() < parse_packet("[1,1,5,1,1]").unwrap());
+    }
+    #[test]
+    fn test_order2() {
+        assert!(parse_packet("[[1],[2,3,4]]").unwrap() < parse_packet("[[1],4]").unwrap());
+    }
+    #[test]
+    fn test_order3() {
+        assert!(parse_packet("[9]").unwrap() > parse_packet("[[8,7,6]]").unwrap());
+    }
+    #[test]
+    fn test_order4() {
+        assert!(parse_packet("[[4,4],4,4]").unwrap() < parse_packet("[[4,4],4,4,4]").unwrap());
+    }
+    #[test]
+    fn test_order5() {
+        assert!(parse_packet("[7,7,7,7]").unwrap() > parse_packet("[7,7,7]").unwrap());
+    }
+    #[test]
+    fn test_order6() {
+        assert!(parse_packet("[]").unwrap() < parse_packet("[3]").unwrap());
+    }
+    #[test]
+    fn test_order7() {
+        assert!(parse_packet("[[[]]]").unwrap() > parse_packet("[]").unwrap());
+    }
+    #[test]
+    fn test_order8() {
+        assert!(
+            parse_packet("[1,[2,[3,[4,[5,6,7]]]],8,9]").unwrap()
+                > parse_packet("[1,[2,[3,[4,[5,6,0]]]],8,9]").unwrap()
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Example, Grid, ParseError, Point, Result, Solver, Vec2};
+
+/*
+  4     5  5
+  9     0  0
+  4     0  3
+0 ......+...
+1 ..........
+2 ..........
+3 ..........
+4 ....#...##
+5 ....#...#.   --> X
+6 ..###...#.  |
+7 ........#.  V
+8 ........#.
+9 #########.  Y
+*/
+
+/// Where sand comes from.
+const SOURCE: Point = Point::new(500, 0);
+
+/// Rows under the source held in a grid, deeper tiles being kept in a set.
+const DENSE_ROWS: isize = 1024;
+
+#[derive(Clone, Debug)]
+pub struct Map {
+    /// Tiles of rock or sand of the top rows, translated by `origin`.
+    blocked: Grid<bool>,
+    /// Position of the top-left tile of `blocked` in the scan.
+    origin: Point,
+    /// Tiles of rock or sand below `blocked`.
+    deep: HashSet<Point>,
+    lowest_rock: isize,
+}
+
+impl Map {
+    pub fn tile_empty(&self, p: Point, part2: bool) -> bool {
+        if part2 && p.y >= self.lowest_rock + 2 {
+            return false;
+        };
+        match self.blocked.get(p - self.origin) {
+            Some(&blocked) => !blocked,
+            None => !self.deep.contains(&p),
+        }
+    }
+
+    fn block(&mut self, p: Point) {
+        match self.blocked.get_mut(p - self.origin) {
+            Some(blocked) => *blocked = true,
+            None => {
+                self.deep.insert(p);
+            }
+        }
+    }
+}
+
+/// Parses a line of the scan into the corners of a rock path.
+fn parse_path(line: &str) -> std::result::Result<Vec<Point>, ParseError> {
+    let mut path: Vec<Point> = vec![];
+    for c in line.split(" -> ") {
+        let (x, y) = c
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(line, c, "a point should have 2 coords"))?;
+        let coord = |s: &str| {
+            s.parse::<u32>()
+                .map(|n| n as isize)
+                .map_err(|_| ParseError::new(line, s, "invalid coordinate"))
+        };
+        let new = Point::new(coord(x)?, coord(y)?);
+        if let Some(cur) = path.last() {
+            if new.x != cur.x && new.y != cur.y {
+                return Err(ParseError::new(line, c, "no diagonals, please!"));
+            }
+        }
+        path.push(new);
+    }
+    Ok(path)
+}
+
+pub fn parse_map(content: &str) -> std::result::Result<Map, ParseError> {
+    let paths = aoc_common::parse_lines(content, parse_path)?;
+    let rocks = paths.iter().flatten();
+    let lowest_rock = rocks.clone().map(|p| p.y).max().unwrap_or(0);
+
+    // Sand piles up in a triangle under the source, at most down to the floor: rocks
+    // outside of it are left out
+    let rows = (lowest_rock + 2).min(DENSE_ROWS);
+    let mut map = Map {
+        blocked: Grid::new((2 * rows + 1) as usize, (rows + 1) as usize, false),
+        origin: Point::new(SOURCE.x - rows, 0),
+        deep: HashSet::new(),
+        lowest_rock,
+    };
+    for path in &paths {
+        for (&cur, &new) in path.iter().zip(&path[1..]) {
+            // Either vertical or horizontal
+            let (min, max) = (cur.min(new), cur.max(new));
+            if min.y == max.y {
+                let y = min.y;
+                for x in min.x.max(SOURCE.x - y)..=max.x.min(SOURCE.x + y) {
+                    map.block(Point::new(x, y));
+                }
+            } else {
+                let x = min.x;
+                for y in min.y.max((x - SOURCE.x).abs())..=max.y {
+                    map.block(Point::new(x, y));
+                }
+            }
+        }
+    }
+    Ok(map)
+}
+
+/// Drops sand until it stops coming to rest, returning how much did.
+fn count_sand(map: &mut Map, part2: bool) -> u32 {
+    let mut cnt = 0;
+    'outer: loop {
+        if !map.tile_empty(SOURCE, part2) {
+            break;
+        }
+        let mut p = SOURCE;
+        let depth = if part2 {
+            map.lowest_rock + 2
+        } else {
+            map.lowest_rock
+        };
+        for _ in 1..=depth {
+            // straight down, then left, then right
+            let next = [
+                Vec2::DOWN,
+                Vec2::DOWN + Vec2::LEFT,
+                Vec2::DOWN + Vec2::RIGHT,
+            ]
+            .map(|d| p + d)
+            .into_iter()
+            .find(|&n| map.tile_empty(n, part2));
+            if let Some(next) = next {
+                p = next;
+                continue;
+            }
+            map.block(p);
+            cnt += 1;
+            continue 'outer;
+        }
+        break;
+    }
+
+    cnt
+}
+
+pub fn count_sand1(mut map: Map) -> u32 {
+    count_sand(&mut map, false)
+}
+
+pub fn count_sand2(mut map: Map) -> u32 {
+    count_sand(&mut map, true)
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: u8 = 14;
+
+    const EXAMPLE: Example = Example {
+        input: "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+",
+        part1: "24",
+        part2: "93",
+    };
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Map> {
+        Ok(parse_map(input)?)
+    }
+
+    fn part1(map: &Map) -> Result<Answer> {
+        Ok(count_sand1(map.clone()).into())
+    }
+
+    fn part2(map: &Map) -> Result<Answer> {
+        Ok(count_sand2(map.clone()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        aoc_common::check_example::<Day14>();
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = parse_map("498,4 -> 498,6\n503,4 -> 502,x").unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 14, "x"));
+
+        let e = parse_map("498,4 -> 497,6").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (10, "497,6"));
+
+        let e = parse_map("498,4 -> 498").unwrap_err();
+        assert_eq!((e.column, e.text.as_str()), (10, "498"));
+    }
+
+    #[test]
+    fn test_far_rocks() {
+        // Only the part of the rocks within reach of the sand is held
+        let map = parse_map("4000000000,5 -> 4000000000,6\n0,7 -> 4000000000,7").unwrap();
+        assert_eq!(count_sand1(map.clone()), 49);
+        assert_eq!(count_sand2(map), 49);
+
+        // Below the rows of the grid
+        let map = parse_map("499,100000 -> 501,100000").unwrap();
+        assert_eq!(count_sand1(map), 1);
+    }
+}